@@ -45,6 +45,7 @@
 #include "draco/mesh/mesh_features.h"
 #include "draco/mesh/mesh_splitter.h"
 #include "draco/mesh/mesh_utils.h"
+#include "draco/mesh/mesh_vertex_cache_optimizer.h"
 #include "draco/metadata/property_attribute.h"
 #include "draco/scene/instance_array.h"
 #include "draco/scene/scene_indices.h"
@@ -281,6 +282,7 @@ class GltfAsset {
 
   void set_copyright(const std::string &copyright) { copyright_ = copyright; }
   std::string copyright() const { return copyright_; }
+  void set_generator(const std::string &generator) { generator_ = generator; }
   std::string generator() const { return generator_; }
   std::string version() const { return version_; }
   std::string buffer_name() const { return buffer_name_; }
@@ -3549,7 +3551,8 @@ void GltfAsset::SetCopyrightFromMesh(const Mesh &mesh) {
 const char GltfEncoder::kDracoMetadataGltfAttributeName[] =
     "//GLTF/ApplicationSpecificAttributeName";
 
-GltfEncoder::GltfEncoder() : out_buffer_(nullptr), output_type_(COMPACT) {}
+GltfEncoder::GltfEncoder()
+    : out_buffer_(nullptr), output_type_(COMPACT), optimize_for_gpu_(false) {}
 
 template <typename T>
 bool GltfEncoder::EncodeToFile(const T &geometry, const std::string &file_name,
@@ -3600,6 +3603,9 @@ Status GltfEncoder::EncodeFile(const T &geometry, const std::string &filename,
 
   GltfAsset gltf_asset;
   gltf_asset.set_copyright(copyright_);
+  if (!generator_.empty()) {
+    gltf_asset.set_generator(generator_);
+  }
   gltf_asset.set_output_type(output_type_);
 
   if (extension == "gltf") {
@@ -3614,7 +3620,7 @@ Status GltfEncoder::EncodeFile(const T &geometry, const std::string &filename,
 
   // Encode the geometry into a buffer.
   EncoderBuffer buffer;
-  DRACO_RETURN_IF_ERROR(EncodeToBuffer(geometry, &gltf_asset, &buffer));
+  DRACO_RETURN_IF_ERROR(EncodeGeometryToBuffer(geometry, &gltf_asset, &buffer));
   if (extension == "glb") {
     return WriteGlbFile(gltf_asset, buffer, filename);
   }
@@ -3630,10 +3636,13 @@ Status GltfEncoder::EncodeToBuffer(const T &geometry,
   gltf_asset.buffer_name("");
   gltf_asset.set_add_images_to_buffer(true);
   gltf_asset.set_copyright(copyright_);
+  if (!generator_.empty()) {
+    gltf_asset.set_generator(generator_);
+  }
 
   // Encode the geometry into a buffer.
   EncoderBuffer buffer;
-  DRACO_RETURN_IF_ERROR(EncodeToBuffer(geometry, &gltf_asset, &buffer));
+  DRACO_RETURN_IF_ERROR(EncodeGeometryToBuffer(geometry, &gltf_asset, &buffer));
 
   // Define a function for concatenating GLB file chunks into a single buffer.
   const auto encode_chunk_to_buffer =
@@ -3696,6 +3705,45 @@ Status GltfEncoder::EncodeToBuffer(const Scene &scene, GltfAsset *gltf_asset,
   return gltf_asset->Output(out_buffer);
 }
 
+template <typename T>
+Status GltfEncoder::EncodeGeometryToBuffer(const T &geometry,
+                                           GltfAsset *gltf_asset,
+                                           EncoderBuffer *out_buffer) {
+  if (!optimize_for_gpu_) {
+    return EncodeToBuffer(geometry, gltf_asset, out_buffer);
+  }
+  DRACO_ASSIGN_OR_RETURN(const std::unique_ptr<T> optimized_geometry,
+                         OptimizeForGpu(geometry));
+  return EncodeToBuffer(*optimized_geometry, gltf_asset, out_buffer);
+}
+
+StatusOr<std::unique_ptr<Mesh>> GltfEncoder::OptimizeForGpu(const Mesh &mesh) {
+  std::unique_ptr<Mesh> optimized_mesh(new Mesh());
+  optimized_mesh->Copy(mesh);
+  // Draco compression reorders faces and points on its own, so the
+  // optimization would be lost for compressed meshes.
+  if (!optimized_mesh->IsCompressionEnabled()) {
+    DRACO_RETURN_IF_ERROR(MeshVertexCacheOptimizer::Optimize(
+        optimized_mesh.get(), MeshVertexCacheOptimizerOptions()));
+  }
+  return std::move(optimized_mesh);
+}
+
+StatusOr<std::unique_ptr<Scene>> GltfEncoder::OptimizeForGpu(
+    const Scene &scene) {
+  std::unique_ptr<Scene> optimized_scene(new Scene());
+  optimized_scene->Copy(scene);
+  for (MeshIndex i(0); i < optimized_scene->NumMeshes(); ++i) {
+    Mesh &mesh = optimized_scene->GetMesh(i);
+    if (mesh.IsCompressionEnabled()) {
+      continue;
+    }
+    DRACO_RETURN_IF_ERROR(MeshVertexCacheOptimizer::Optimize(
+        &mesh, MeshVertexCacheOptimizerOptions()));
+  }
+  return std::move(optimized_scene);
+}
+
 void GltfEncoder::SetJsonWriterMode(class GltfAsset *gltf_asset) {
   if (gltf_asset->output_type() == COMPACT &&
       gltf_asset->add_images_to_buffer()) {
@@ -21,11 +21,13 @@
 #include <functional>
 #include <iostream>
 #include <map>
+#include <memory>
 #include <sstream>
 #include <string>
 #include <vector>
 
 #include "draco/core/encoder_buffer.h"
+#include "draco/core/status_or.h"
 #include "draco/io/file_writer_factory.h"
 #include "draco/io/file_writer_interface.h"
 #include "draco/io/texture_io.h"
@@ -87,6 +89,19 @@ class GltfEncoder {
   void set_copyright(const std::string &copyright) { copyright_ = copyright; }
   std::string copyright() const { return copyright_; }
 
+  // Sets the generator string stored in the asset of the glTF output. If
+  // empty, the default "draco_decoder" generator string is used.
+  void set_generator(const std::string &generator) { generator_ = generator; }
+  std::string generator() const { return generator_; }
+
+  // If set, faces and points of the encoded meshes are reordered for better
+  // GPU vertex cache utilization. The input geometry is not modified. Only
+  // meshes without Draco compression are affected, because the Draco encoder
+  // reorders faces and points on its own. See MeshVertexCacheOptimizer for
+  // more details.
+  void set_optimize_for_gpu(bool flag) { optimize_for_gpu_ = flag; }
+  bool optimize_for_gpu() const { return optimize_for_gpu_; }
+
   // The name of the attribute metadata that contains the glTF attribute
   // name. For application-specific generic attributes, if the metadata for
   // an attribute contains this key, then the value will be used as the
@@ -100,6 +115,17 @@ class GltfEncoder {
   Status EncodeToBuffer(const Scene &scene, class GltfAsset *gltf_asset,
                         EncoderBuffer *out_buffer);
 
+  // Encodes |geometry| into a buffer. If |optimize_for_gpu_| is set, a copy of
+  // |geometry| optimized for GPU rendering is encoded instead.
+  template <typename T>
+  Status EncodeGeometryToBuffer(const T &geometry, class GltfAsset *gltf_asset,
+                                EncoderBuffer *out_buffer);
+
+  // Returns a copy of |mesh| or of |scene| with all its meshes optimized for
+  // GPU rendering. Meshes with enabled Draco compression are copied as is.
+  static StatusOr<std::unique_ptr<Mesh>> OptimizeForGpu(const Mesh &mesh);
+  static StatusOr<std::unique_ptr<Scene>> OptimizeForGpu(const Scene &scene);
+
   // Sets appropriate Json writer mode based on the provided |gltf_asset|
   // options.
   static void SetJsonWriterMode(class GltfAsset *gltf_asset);
@@ -130,6 +156,8 @@ class GltfEncoder {
   EncoderBuffer *out_buffer_;
   OutputType output_type_;
   std::string copyright_;
+  std::string generator_;
+  bool optimize_for_gpu_;
 };
 
 }  // namespace draco
@@ -36,6 +36,7 @@
 #include "draco/io/texture_io.h"
 #include "draco/material/material_utils.h"
 #include "draco/mesh/mesh_utils.h"
+#include "draco/mesh/mesh_vertex_cache_optimizer.h"
 #include "draco/scene/mesh_group.h"
 #include "draco/scene/scene.h"
 #include "draco/scene/scene_utils.h"
@@ -959,6 +960,35 @@ TEST_F(GltfEncoderTest, TestOutputType) {
   ASSERT_GT(verbose_gltf_size, default_gltf_size);
 }
 
+TEST_F(GltfEncoderTest, TestOptimizeForGpu) {
+  const std::unique_ptr<Mesh> mesh(ReadMeshFromTestFile("bun_zipper.ply"));
+  ASSERT_NE(mesh, nullptr);
+  const int kCacheSize = 16;
+  const float input_acmr =
+      MeshVertexCacheOptimizer::ComputeAverageCacheMissRatio(*mesh,
+                                                             kCacheSize);
+
+  GltfEncoder encoder;
+  encoder.set_optimize_for_gpu(true);
+  EncoderBuffer buffer;
+  DRACO_ASSERT_OK(encoder.EncodeToBuffer(*mesh, &buffer));
+
+  // Check that the encoded faces have better vertex cache utilization and
+  // that the input mesh has not been modified.
+  DecoderBuffer decoder_buffer;
+  decoder_buffer.Init(buffer.data(), buffer.size());
+  GltfDecoder decoder;
+  DRACO_ASSIGN_OR_ASSERT(std::unique_ptr<Mesh> decoded_mesh,
+                         decoder.DecodeFromBuffer(&decoder_buffer));
+  ASSERT_EQ(decoded_mesh->num_faces(), mesh->num_faces());
+  ASSERT_LT(MeshVertexCacheOptimizer::ComputeAverageCacheMissRatio(
+                *decoded_mesh, kCacheSize),
+            input_acmr);
+  ASSERT_EQ(MeshVertexCacheOptimizer::ComputeAverageCacheMissRatio(
+                *mesh, kCacheSize),
+            input_acmr);
+}
+
 // Tests copying the name of the input texture file to the encoded texture file.
 TEST_F(GltfEncoderTest, CopyTextureName) {
   std::unique_ptr<draco::Mesh> mesh =
@@ -1538,6 +1568,27 @@ TEST_F(GltfEncoderTest, CopyrightAssetIsEncoded) {
   }
 }
 
+TEST_F(GltfEncoderTest, GeneratorAssetIsEncoded) {
+  const std::unique_ptr<Mesh> mesh(ReadMeshFromTestFile("cube_att.obj"));
+  ASSERT_NE(mesh, nullptr);
+
+  // Check that the default generator string is encoded.
+  GltfEncoder encoder;
+  EncoderBuffer default_buffer;
+  DRACO_ASSERT_OK(encoder.EncodeToBuffer(*mesh, &default_buffer));
+  const std::string default_data(default_buffer.data(), default_buffer.size());
+  ASSERT_NE(default_data.find("\"draco_decoder\""), std::string::npos);
+
+  // Check that a custom generator string replaces the default one.
+  encoder.set_generator("Test Generator 1.0");
+  ASSERT_EQ(encoder.generator(), "Test Generator 1.0");
+  EncoderBuffer buffer;
+  DRACO_ASSERT_OK(encoder.EncodeToBuffer(*mesh, &buffer));
+  const std::string data(buffer.data(), buffer.size());
+  ASSERT_NE(data.find("\"Test Generator 1.0\""), std::string::npos);
+  ASSERT_EQ(data.find("\"draco_decoder\""), std::string::npos);
+}
+
 // Tests that a scene with lights can be encoded into a file.
 TEST_F(GltfEncoderTest, EncodeLights) {
   const std::string file_name = "sphere_lights.gltf";
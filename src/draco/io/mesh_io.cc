@@ -66,6 +66,9 @@ StatusOr<std::unique_ptr<Mesh>> ReadMeshFromFile(
     ObjDecoder obj_decoder;
     obj_decoder.set_use_metadata(options.GetBool("use_metadata", false));
     obj_decoder.set_preserve_polygons(options.GetBool("preserve_polygons"));
+#ifdef DRACO_TRANSCODER_SUPPORTED
+    obj_decoder.set_load_materials(options.GetBool("load_materials"));
+#endif
     const Status obj_status =
         obj_decoder.DecodeFromFile(file_name, mesh.get(), mesh_files);
     if (!obj_status.ok()) {
@@ -76,6 +79,8 @@ StatusOr<std::unique_ptr<Mesh>> ReadMeshFromFile(
   if (extension == "ply") {
     // Stanford PLY file format.
     PlyDecoder ply_decoder;
+    ply_decoder.set_decode_generic_properties(
+        options.GetBool("decode_generic_properties"));
     DRACO_RETURN_IF_ERROR(ply_decoder.DecodeFromFile(file_name, mesh.get()));
     return std::move(mesh);
   }
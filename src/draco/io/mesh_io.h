@@ -93,6 +93,12 @@ StatusOr<std::unique_ptr<Mesh>> ReadMeshFromFile(const std::string &file_name,
 // Reads a mesh from a file. Reading is configured with |options|:
 // use_metadata  : Read obj file info like material names and object names into
 // metadata. Default is false.
+// load_materials: Read materials of obj files into the material library of the
+// mesh. Only available when DRACO_TRANSCODER_SUPPORTED is defined. Default is
+// false.
+// decode_generic_properties: Read unrecognized vertex properties of ply files
+// into generic attributes. See PlyDecoder::set_decode_generic_properties().
+// Default is false.
 // The second form returns the files associated with the mesh via the
 // |mesh_files| argument.
 // Returns nullptr with an error status if the decoding failed.
@@ -15,14 +15,20 @@
 #include "draco/io/obj_decoder.h"
 
 #include <algorithm>
+#include <array>
 #include <cctype>
 #include <cmath>
 #include <utility>
+#include <vector>
 
 #include "draco/io/file_utils.h"
 #include "draco/io/parser_utils.h"
 #include "draco/metadata/geometry_metadata.h"
 
+#ifdef DRACO_TRANSCODER_SUPPORTED
+#include "draco/io/texture_io.h"
+#endif
+
 namespace draco {
 
 ObjDecoder::ObjDecoder()
@@ -44,6 +50,9 @@ ObjDecoder::ObjDecoder()
       use_metadata_(false),
       preserve_polygons_(false),
       has_polygons_(false),
+#ifdef DRACO_TRANSCODER_SUPPORTED
+      load_materials_(false),
+#endif
       mesh_files_(nullptr),
       out_mesh_(nullptr),
       out_point_cloud_(nullptr) {}
@@ -93,6 +102,9 @@ Status ObjDecoder::DecodeInternal() {
   counting_mode_ = true;
   ResetCounters();
   material_name_to_id_.clear();
+#ifdef DRACO_TRANSCODER_SUPPORTED
+  material_textures_.clear();
+#endif
   last_sub_obj_id_ = 0;
   // Parse all lines.
   Status status(Status::OK);
@@ -182,7 +194,30 @@ Status ObjDecoder::DecodeInternal() {
   }
   if (num_materials_ > 0 && num_obj_faces_ > 0) {
     GeometryAttribute va;
-    const auto geometry_attribute_type = GeometryAttribute::GENERIC;
+    auto geometry_attribute_type = GeometryAttribute::GENERIC;
+#ifdef DRACO_TRANSCODER_SUPPORTED
+    if (load_materials_ && out_mesh_) {
+      // Materials that are used in the obj file but that are not defined in
+      // the material file get default properties.
+      // The materials must be visited in the order of their ids, because
+      // MutableMaterial() creates all materials up to the requested id.
+      std::vector<const std::string *> material_names(num_materials_,
+                                                      nullptr);
+      for (const auto &itr : material_name_to_id_) {
+        material_names[itr.second] = &itr.first;
+      }
+      MaterialLibrary &material_library = out_mesh_->GetMaterialLibrary();
+      const int num_defined_materials = material_library.NumMaterials();
+      for (int i = num_defined_materials; i < num_materials_; ++i) {
+        Material *const material = material_library.MutableMaterial(i);
+        if (material_names[i] != nullptr) {
+          material->SetName(*material_names[i]);
+        }
+        material->SetMetallicFactor(0.f);
+      }
+      geometry_attribute_type = GeometryAttribute::MATERIAL;
+    }
+#endif
     if (num_materials_ < 256) {
       va.Init(geometry_attribute_type, nullptr, 1, DT_UINT8, false, 1, 0);
     } else if (num_materials_ < (1 << 16)) {
@@ -701,6 +736,10 @@ bool ObjDecoder::ParseMaterialFile(const std::string &file_name,
     return false;
   }
 
+#ifdef DRACO_TRANSCODER_SUPPORTED
+  material_file_full_path_ = full_path;
+#endif
+
   // Backup the original decoder buffer.
   DecoderBuffer old_buffer = buffer_;
 
@@ -739,10 +778,129 @@ bool ObjDecoder::ParseMaterialFileDefinition(Status * /* status */) {
     }
     // Add new material to our map.
     material_name_to_id_[str] = num_materials_++;
+#ifdef DRACO_TRANSCODER_SUPPORTED
+    if (load_materials_ && out_mesh_) {
+      Material *const material =
+          out_mesh_->GetMaterialLibrary().MutableMaterial(num_materials_ - 1);
+      material->SetName(str);
+      // Materials in obj files describe non-metallic surfaces by default.
+      material->SetMetallicFactor(0.f);
+    }
+#endif
+    return true;
   }
+#ifdef DRACO_TRANSCODER_SUPPORTED
+  if (load_materials_ && out_mesh_ && num_materials_ > 0) {
+    ParseMaterialProperty(
+        str,
+        out_mesh_->GetMaterialLibrary().MutableMaterial(num_materials_ - 1));
+  }
+#endif
   return true;
 }
 
+#ifdef DRACO_TRANSCODER_SUPPORTED
+void ObjDecoder::ParseMaterialProperty(const std::string &property_name,
+                                       Material *material) {
+  DecoderBuffer line_buffer = parser::ParseLineIntoDecoderBuffer(buffer());
+  // Parses up to three float values from the rest of the line. Returns the
+  // number of parsed values.
+  std::array<float, 3> values;
+  const auto parse_values = [&line_buffer, &values]() {
+    int num_values = 0;
+    while (num_values < 3) {
+      parser::SkipWhitespace(&line_buffer);
+      if (!parser::ParseFloat(&line_buffer, &values[num_values])) {
+        break;
+      }
+      ++num_values;
+    }
+    return num_values;
+  };
+
+  if (property_name == "Kd") {
+    if (parse_values() == 3) {
+      const Vector4f &color = material->GetColorFactor();
+      material->SetColorFactor(
+          Vector4f(values[0], values[1], values[2], color[3]));
+    }
+  } else if (property_name == "d" || property_name == "Tr") {
+    if (parse_values() >= 1) {
+      // Dissolve (d) is the opacity and transparency (Tr) is its complement.
+      const float alpha = property_name == "d" ? values[0] : 1.f - values[0];
+      const Vector4f &color = material->GetColorFactor();
+      material->SetColorFactor(Vector4f(color[0], color[1], color[2], alpha));
+      material->SetTransparencyMode(alpha < 1.f
+                                        ? Material::TRANSPARENCY_BLEND
+                                        : Material::TRANSPARENCY_OPAQUE);
+    }
+  } else if (property_name == "Ke") {
+    if (parse_values() == 3) {
+      // glTF emissive factors are limited to [0, 1].
+      for (float &value : values) {
+        value = std::min(std::max(value, 0.f), 1.f);
+      }
+      material->SetEmissiveFactor(Vector3f(values[0], values[1], values[2]));
+    }
+  } else if (property_name == "Ni") {
+    if (parse_values() >= 1) {
+      material->SetHasIor(true);
+      material->SetIor(values[0]);
+    }
+  } else if (property_name == "Pr") {
+    if (parse_values() >= 1) {
+      material->SetRoughnessFactor(values[0]);
+    }
+  } else if (property_name == "Pm") {
+    if (parse_values() >= 1) {
+      material->SetMetallicFactor(values[0]);
+    }
+  } else if (property_name == "map_Kd" || property_name == "map_Ke") {
+    // Texture statements can contain options (e.g. "-s 1 1 1") before the
+    // file name. The file name is expected to be the last token on the line.
+    std::string texture_file_name;
+    std::string token;
+    while (parser::ParseString(&line_buffer, &token) && !token.empty()) {
+      texture_file_name = token;
+    }
+    if (!texture_file_name.empty()) {
+      ParseMaterialTexture(texture_file_name,
+                           property_name == "map_Kd" ? TextureMap::COLOR
+                                                     : TextureMap::EMISSIVE,
+                           material);
+    }
+  }
+}
+
+void ObjDecoder::ParseMaterialTexture(const std::string &texture_file_name,
+                                      TextureMap::Type type,
+                                      Material *material) {
+  const std::string full_path =
+      GetFullPath(texture_file_name, material_file_full_path_);
+  Texture *texture = nullptr;
+  const auto it = material_textures_.find(full_path);
+  if (it != material_textures_.end()) {
+    texture = it->second;
+  } else {
+    StatusOr<std::unique_ptr<Texture>> texture_or =
+        ReadTextureFromFile(full_path);
+    if (!texture_or.ok()) {
+      // Silently ignore missing or unsupported textures like the rest of the
+      // problems with material files.
+      return;
+    }
+    texture = texture_or.value().get();
+    out_mesh_->GetMaterialLibrary().MutableTextureLibrary().PushTexture(
+        std::move(texture_or).value());
+    material_textures_[full_path] = texture;
+    if (mesh_files_) {
+      mesh_files_->push_back(full_path);
+    }
+  }
+  material->SetTextureMap(texture, type, /* tex_coord_index = */ 0);
+}
+#endif
+
 // Methods Triangulate() and IsNewEdge() are used for polygon triangulation and
 // representation as an attribute for reconstruction in the decoder.
 //
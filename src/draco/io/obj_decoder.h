@@ -56,6 +56,15 @@ class ObjDecoder {
   void set_use_metadata(bool flag) { use_metadata_ = flag; }
   // Enables preservation of polygons.
   void set_preserve_polygons(bool flag) { preserve_polygons_ = flag; }
+#ifdef DRACO_TRANSCODER_SUPPORTED
+  // Flag for loading materials defined in the material library (.mtl file)
+  // into the material library of the decoded mesh. Diffuse, specular and
+  // emissive colors, opacity, index of refraction, PBR factors and color and
+  // emissive textures are supported. When enabled, the per-face material ids
+  // are stored in a GeometryAttribute::MATERIAL attribute.
+  // Default: false
+  void set_load_materials(bool flag) { load_materials_ = flag; }
+#endif
 
  protected:
   Status DecodeInternal();
@@ -93,6 +102,16 @@ class ObjDecoder {
   // Parses material file definitions from a separate file.
   bool ParseMaterialFile(const std::string &file_name, Status *status);
   bool ParseMaterialFileDefinition(Status *status);
+#ifdef DRACO_TRANSCODER_SUPPORTED
+  // Parses a property of the last material defined in the material file.
+  // |property_name| is the name of the already parsed property.
+  void ParseMaterialProperty(const std::string &property_name,
+                             Material *material);
+  // Loads texture |texture_file_name| referenced from the material file and
+  // assigns it to |material| as a texture map of |type|.
+  void ParseMaterialTexture(const std::string &texture_file_name,
+                            TextureMap::Type type, Material *material);
+#endif
 
   // Methods related to polygon triangulation and preservation.
   static int Triangulate(int tri_index, int tri_corner);
@@ -132,6 +151,13 @@ class ObjDecoder {
   bool preserve_polygons_;
   bool has_polygons_;
 
+#ifdef DRACO_TRANSCODER_SUPPORTED
+  bool load_materials_;
+  std::string material_file_full_path_;
+  // Textures loaded from the material file mapped by their full path.
+  std::unordered_map<std::string, Texture *> material_textures_;
+#endif
+
   std::vector<std::string> *mesh_files_;
 
   DecoderBuffer buffer_;
@@ -281,6 +281,112 @@ TEST_F(ObjDecoderTest, WrongAttributeMapping) {
   ASSERT_EQ(mesh->attribute(0)->size(), 3);
 }
 
+#ifdef DRACO_TRANSCODER_SUPPORTED
+TEST_F(ObjDecoderTest, LoadMaterials) {
+  // Tests that materials from the .mtl file are loaded into the material
+  // library of the decoded mesh.
+  const std::string path = GetTestFileFullPath("mat_test.obj");
+  ObjDecoder decoder;
+  decoder.set_load_materials(true);
+  std::unique_ptr<Mesh> mesh(new Mesh());
+  DRACO_ASSERT_OK(decoder.DecodeFromFile(path, mesh.get()));
+  const PointAttribute *const mat_att =
+      mesh->GetNamedAttribute(GeometryAttribute::MATERIAL);
+  ASSERT_NE(mat_att, nullptr);
+
+  const MaterialLibrary &library = mesh->GetMaterialLibrary();
+  ASSERT_GT(library.NumMaterials(), 0);
+  // Each material id stored in the material attribute must point to an
+  // existing material.
+  for (AttributeValueIndex i(0); i < mat_att->size(); ++i) {
+    uint32_t mat_index = 0;
+    mat_att->GetValue(i, &mat_index);
+    ASSERT_LT(mat_index, library.NumMaterials());
+  }
+
+  const Material *mat0 = nullptr;
+  const Material *mat26 = nullptr;
+  const Material *mat25 = nullptr;
+  const Material *unknown_mat = nullptr;
+  for (int i = 0; i < library.NumMaterials(); ++i) {
+    const Material *const material = library.GetMaterial(i);
+    if (material->GetName() == "mat0") {
+      mat0 = material;
+    } else if (material->GetName() == "mat26") {
+      mat26 = material;
+    } else if (material->GetName() == "mat mat 25") {
+      mat25 = material;
+    } else if (material->GetName() == "unknown_mat_in_mtl_file") {
+      unknown_mat = material;
+    }
+  }
+  ASSERT_NE(mat0, nullptr);
+  ASSERT_NE(mat26, nullptr);
+  // Materials that are used in the .obj file but that are not defined in the
+  // .mtl file get default non-metallic properties.
+  ASSERT_NE(mat25, nullptr);
+  ASSERT_NE(unknown_mat, nullptr);
+  ASSERT_EQ(mat25->GetMetallicFactor(), 0.f);
+  ASSERT_EQ(unknown_mat->GetMetallicFactor(), 0.f);
+
+  ASSERT_EQ(mat0->GetColorFactor(), Vector4f(0.73f, 0.41f, 0.78f, 1.f));
+  ASSERT_EQ(mat0->GetMetallicFactor(), 0.f);
+  ASSERT_EQ(mat0->GetTransparencyMode(), Material::TRANSPARENCY_OPAQUE);
+
+  ASSERT_EQ(mat26->GetColorFactor(), Vector4f(0.92f, 0.95f, 0.94f, 0.4f));
+  ASSERT_EQ(mat26->GetTransparencyMode(), Material::TRANSPARENCY_BLEND);
+  ASSERT_FALSE(mat26->HasSpecular());
+  ASSERT_TRUE(mat26->HasIor());
+  ASSERT_EQ(mat26->GetIor(), 1.5f);
+
+  // Textures referenced by the material file do not exist and are ignored.
+  ASSERT_EQ(library.GetTextureLibrary().NumTextures(), 0);
+}
+
+TEST_F(ObjDecoderTest, LoadMaterialsWithTextures) {
+  // Tests that textures referenced by the .mtl file are loaded and shared
+  // between materials that use the same image file.
+  const std::string path = GetTestFileFullPath("textured_materials.obj");
+  ObjDecoder decoder;
+  decoder.set_load_materials(true);
+  std::unique_ptr<Mesh> mesh(new Mesh());
+  std::vector<std::string> mesh_files;
+  DRACO_ASSERT_OK(decoder.DecodeFromFile(path, mesh.get(), &mesh_files));
+
+  const MaterialLibrary &library = mesh->GetMaterialLibrary();
+  ASSERT_EQ(library.NumMaterials(), 2);
+  ASSERT_EQ(library.GetTextureLibrary().NumTextures(), 2);
+  const Material *const red = library.GetMaterial(0);
+  const Material *const green = library.GetMaterial(1);
+  ASSERT_EQ(red->GetName(), "red");
+  ASSERT_EQ(green->GetName(), "green");
+
+  // Texture options preceding the file name are skipped.
+  const TextureMap *const red_color =
+      red->GetTextureMapByType(TextureMap::COLOR);
+  const TextureMap *const green_color =
+      green->GetTextureMapByType(TextureMap::COLOR);
+  ASSERT_NE(red_color, nullptr);
+  ASSERT_NE(green_color, nullptr);
+  ASSERT_EQ(red_color->texture(), green_color->texture());
+  ASSERT_EQ(red->GetTextureMapByType(TextureMap::EMISSIVE), nullptr);
+  const TextureMap *const green_emissive =
+      green->GetTextureMapByType(TextureMap::EMISSIVE);
+  ASSERT_NE(green_emissive, nullptr);
+  ASSERT_NE(green_emissive->texture(), green_color->texture());
+
+  // Emissive factors are clamped to [0, 1].
+  ASSERT_EQ(red->GetEmissiveFactor(), Vector3f(0.f, 0.f, 0.f));
+  ASSERT_EQ(green->GetEmissiveFactor(), Vector3f(1.f, 0.5f, 1.f));
+
+  // The .mtl file, both images and the .obj file are reported as mesh files.
+  ASSERT_EQ(mesh_files.size(), 4);
+  ASSERT_EQ(mesh_files[1], GetTestFileFullPath("test.png"));
+  ASSERT_EQ(mesh_files[2],
+            GetTestFileFullPath("sphere_Texture1_BaseColor.png"));
+}
+#endif  // DRACO_TRANSCODER_SUPPORTED
+
 TEST_F(ObjDecoderTest, TestObjDecodingAll) {
   // test if we can read all obj that are currently in test folder.
   test_decoding("bunny_norm.obj");
@@ -14,10 +14,13 @@
 //
 #include "draco/io/ply_decoder.h"
 
+#include <initializer_list>
+
 #include "draco/core/macros.h"
 #include "draco/core/status.h"
 #include "draco/io/file_utils.h"
 #include "draco/io/ply_property_reader.h"
+#include "draco/metadata/geometry_metadata.h"
 
 namespace draco {
 namespace {
@@ -34,9 +37,25 @@ int64_t CountNumTriangles(const PlyElement &face_element,
   }
   return num_triangles;
 }
+
+// Positions, normals and colors are read as a single value per vertex, which
+// is not possible for list properties.
+Status CheckPropertiesAreNotLists(
+    std::initializer_list<const PlyProperty *> properties) {
+  for (const PlyProperty *const property : properties) {
+    if (property != nullptr && property->is_list()) {
+      return Status(Status::INVALID_PARAMETER,
+                    "Property '" + property->name() + "' must not be a list");
+    }
+  }
+  return OkStatus();
+}
 }  // namespace
 
-PlyDecoder::PlyDecoder() : out_mesh_(nullptr), out_point_cloud_(nullptr) {}
+PlyDecoder::PlyDecoder()
+    : out_mesh_(nullptr),
+      out_point_cloud_(nullptr),
+      decode_generic_properties_(false) {}
 
 Status PlyDecoder::DecodeFromFile(const std::string &file_name,
                                   Mesh *out_mesh) {
@@ -73,8 +92,25 @@ Status PlyDecoder::DecodeInternal() {
   if (out_mesh_)
     DRACO_RETURN_IF_ERROR(DecodeFaceData(ply_reader.GetElementByName("face")));
   // Decode all attributes.
-  DRACO_RETURN_IF_ERROR(
-      DecodeVertexData(ply_reader.GetElementByName("vertex")));
+  const PlyElement *const vertex_element =
+      ply_reader.GetElementByName("vertex");
+  std::unordered_set<const PlyProperty *> decoded_properties;
+  DRACO_RETURN_IF_ERROR(DecodeVertexData(vertex_element, &decoded_properties));
+  if (out_mesh_) {
+    // Make sure that all faces reference existing vertices.
+    for (FaceIndex f(0); f < out_mesh_->num_faces(); ++f) {
+      for (int c = 0; c < 3; ++c) {
+        if (out_mesh_->face(f)[c] >= out_point_cloud_->num_points()) {
+          return Status(Status::INVALID_PARAMETER,
+                        "Face references an invalid vertex");
+        }
+      }
+    }
+  }
+  if (decode_generic_properties_) {
+    DRACO_RETURN_IF_ERROR(
+        DecodeGenericVertexData(vertex_element, decoded_properties));
+  }
   // In case there are no faces this is just a point cloud which does
   // not require deduplication.
   if (out_mesh_ && out_mesh_->num_faces() != 0) {
@@ -157,7 +193,9 @@ bool PlyDecoder::ReadPropertiesToAttribute(
   return true;
 }
 
-Status PlyDecoder::DecodeVertexData(const PlyElement *vertex_element) {
+Status PlyDecoder::DecodeVertexData(
+    const PlyElement *vertex_element,
+    std::unordered_set<const PlyProperty *> *decoded_properties) {
   if (vertex_element == nullptr) {
     return Status(Status::INVALID_PARAMETER, "vertex_element is null");
   }
@@ -171,6 +209,7 @@ Status PlyDecoder::DecodeVertexData(const PlyElement *vertex_element) {
     // later on).
     return Status(Status::INVALID_PARAMETER, "x, y, or z property is missing");
   }
+  DRACO_RETURN_IF_ERROR(CheckPropertiesAreNotLists({x_prop, y_prop, z_prop}));
   const PointIndex::ValueType num_vertices = vertex_element->num_entries();
   out_point_cloud_->set_num_points(num_vertices);
   // Decode vertex positions.
@@ -196,6 +235,7 @@ Status PlyDecoder::DecodeVertexData(const PlyElement *vertex_element) {
     properties.push_back(x_prop);
     properties.push_back(y_prop);
     properties.push_back(z_prop);
+    decoded_properties->insert(properties.begin(), properties.end());
     if (dt == DT_FLOAT32) {
       ReadPropertiesToAttribute<float>(
           properties, out_point_cloud_->attribute(att_id), num_vertices);
@@ -209,6 +249,8 @@ Status PlyDecoder::DecodeVertexData(const PlyElement *vertex_element) {
   const PlyProperty *const n_x_prop = vertex_element->GetPropertyByName("nx");
   const PlyProperty *const n_y_prop = vertex_element->GetPropertyByName("ny");
   const PlyProperty *const n_z_prop = vertex_element->GetPropertyByName("nz");
+  DRACO_RETURN_IF_ERROR(
+      CheckPropertiesAreNotLists({n_x_prop, n_y_prop, n_z_prop}));
   if (n_x_prop != nullptr && n_y_prop != nullptr && n_z_prop != nullptr) {
     // For now, all normal properties must be set and of type float32
     if (n_x_prop->data_type() == DT_FLOAT32 &&
@@ -217,6 +259,7 @@ Status PlyDecoder::DecodeVertexData(const PlyElement *vertex_element) {
       PlyPropertyReader<float> x_reader(n_x_prop);
       PlyPropertyReader<float> y_reader(n_y_prop);
       PlyPropertyReader<float> z_reader(n_z_prop);
+      decoded_properties->insert({n_x_prop, n_y_prop, n_z_prop});
       GeometryAttribute va;
       va.Init(GeometryAttribute::NORMAL, nullptr, 3, DT_FLOAT32, false,
               sizeof(float) * 3, 0);
@@ -238,6 +281,8 @@ Status PlyDecoder::DecodeVertexData(const PlyElement *vertex_element) {
   const PlyProperty *const g_prop = vertex_element->GetPropertyByName("green");
   const PlyProperty *const b_prop = vertex_element->GetPropertyByName("blue");
   const PlyProperty *const a_prop = vertex_element->GetPropertyByName("alpha");
+  DRACO_RETURN_IF_ERROR(
+      CheckPropertiesAreNotLists({r_prop, g_prop, b_prop, a_prop}));
   if (r_prop) {
     ++num_colors;
   }
@@ -264,6 +309,7 @@ Status PlyDecoder::DecodeVertexData(const PlyElement *vertex_element) {
       }
       color_readers.push_back(std::unique_ptr<PlyPropertyReader<uint8_t>>(
           new PlyPropertyReader<uint8_t>(p)));
+      decoded_properties->insert(p);
     }
     if (g_prop) {
       p = g_prop;
@@ -275,6 +321,7 @@ Status PlyDecoder::DecodeVertexData(const PlyElement *vertex_element) {
       }
       color_readers.push_back(std::unique_ptr<PlyPropertyReader<uint8_t>>(
           new PlyPropertyReader<uint8_t>(p)));
+      decoded_properties->insert(p);
     }
     if (b_prop) {
       p = b_prop;
@@ -286,6 +333,7 @@ Status PlyDecoder::DecodeVertexData(const PlyElement *vertex_element) {
       }
       color_readers.push_back(std::unique_ptr<PlyPropertyReader<uint8_t>>(
           new PlyPropertyReader<uint8_t>(p)));
+      decoded_properties->insert(p);
     }
     if (a_prop) {
       p = a_prop;
@@ -297,6 +345,7 @@ Status PlyDecoder::DecodeVertexData(const PlyElement *vertex_element) {
       }
       color_readers.push_back(std::unique_ptr<PlyPropertyReader<uint8_t>>(
           new PlyPropertyReader<uint8_t>(p)));
+      decoded_properties->insert(p);
     }
 
     GeometryAttribute va;
@@ -317,4 +366,34 @@ Status PlyDecoder::DecodeVertexData(const PlyElement *vertex_element) {
   return OkStatus();
 }
 
+Status PlyDecoder::DecodeGenericVertexData(
+    const PlyElement *vertex_element,
+    const std::unordered_set<const PlyProperty *> &decoded_properties) {
+  const PointIndex::ValueType num_vertices = vertex_element->num_entries();
+  for (int p = 0; p < vertex_element->num_properties(); ++p) {
+    const PlyProperty &prop = vertex_element->property(p);
+    if (prop.is_list()) {
+      // List properties cannot be mapped to point attributes.
+      continue;
+    }
+    if (decoded_properties.count(&prop) > 0) {
+      // The property was already decoded into a different attribute.
+      continue;
+    }
+    GeometryAttribute va;
+    va.Init(GeometryAttribute::GENERIC, nullptr, 1, prop.data_type(), false,
+            prop.data_type_num_bytes(), 0);
+    const int att_id = out_point_cloud_->AddAttribute(va, true, num_vertices);
+    PointAttribute *const att = out_point_cloud_->attribute(att_id);
+    for (PointIndex::ValueType i = 0; i < num_vertices; ++i) {
+      att->SetAttributeValue(AttributeValueIndex(i),
+                             prop.GetDataEntryAddress(i));
+    }
+    std::unique_ptr<AttributeMetadata> metadata(new AttributeMetadata());
+    metadata->AddEntryString("name", prop.name());
+    out_point_cloud_->AddAttributeMetadata(att_id, std::move(metadata));
+  }
+  return OkStatus();
+}
+
 }  // namespace draco
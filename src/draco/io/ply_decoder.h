@@ -16,6 +16,7 @@
 #define DRACO_IO_PLY_DECODER_H_
 
 #include <string>
+#include <unordered_set>
 
 #include "draco/core/decoder_buffer.h"
 #include "draco/core/status.h"
@@ -30,7 +31,8 @@ namespace draco {
 // TODO(b/34330853): The current implementation assumes that the input vertices
 // are defined with x, y, z properties. The decoder also reads uint8 red, green,
 // blue, alpha color information, float32 defined as nx, ny, nz properties, but
-// all other attributes are ignored for now.
+// all other attributes are ignored unless set_decode_generic_properties() is
+// used.
 class PlyDecoder {
  public:
   PlyDecoder();
@@ -43,13 +45,33 @@ class PlyDecoder {
   Status DecodeFromBuffer(DecoderBuffer *buffer, Mesh *out_mesh);
   Status DecodeFromBuffer(DecoderBuffer *buffer, PointCloud *out_point_cloud);
 
+  // Flag that can mark the decoder to decode all vertex properties that are
+  // not decoded as positions, normals or colors (e.g. confidence or intensity
+  // of scanned points, or normals of an unsupported data type). Each such
+  // property is stored in a separate single component
+  // GeometryAttribute::GENERIC attribute with the name of the property stored
+  // in the "name" entry of the attribute metadata.
+  // Default: false
+  void set_decode_generic_properties(bool flag) {
+    decode_generic_properties_ = flag;
+  }
+
  protected:
   Status DecodeInternal();
   DecoderBuffer *buffer() { return &buffer_; }
 
  private:
   Status DecodeFaceData(const PlyElement *face_element);
-  Status DecodeVertexData(const PlyElement *vertex_element);
+  // Decodes positions, normals and colors. All vertex properties that were
+  // decoded are added to |decoded_properties|.
+  Status DecodeVertexData(
+      const PlyElement *vertex_element,
+      std::unordered_set<const PlyProperty *> *decoded_properties);
+  // Decodes all non-list vertex properties that are not in
+  // |decoded_properties| into generic attributes.
+  Status DecodeGenericVertexData(
+      const PlyElement *vertex_element,
+      const std::unordered_set<const PlyProperty *> &decoded_properties);
 
   template <typename DataTypeT>
   bool ReadPropertiesToAttribute(
@@ -62,6 +84,8 @@ class PlyDecoder {
   // always set but |out_mesh_| is optional.
   Mesh *out_mesh_;
   PointCloud *out_point_cloud_;
+
+  bool decode_generic_properties_;
 };
 
 }  // namespace draco
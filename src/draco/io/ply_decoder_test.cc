@@ -74,6 +74,105 @@ TEST_F(PlyDecoderTest, TestPlyNormals) {
   ASSERT_EQ(att->size(), 6);  // 6 unique normal values.
 }
 
+TEST_F(PlyDecoderTest, TestPlyGenericProperties) {
+  // Tests that unrecognized vertex properties are decoded into generic
+  // attributes when requested.
+  const std::string path = GetTestFileFullPath("test_generic_properties.ply");
+  PointCloud pc;
+  PlyDecoder decoder;
+  DRACO_ASSERT_OK(decoder.DecodeFromFile(path, &pc));
+  // By default the extra properties are ignored.
+  ASSERT_EQ(pc.num_attributes(), 1);
+
+  PointCloud pc_generic;
+  decoder.set_decode_generic_properties(true);
+  DRACO_ASSERT_OK(decoder.DecodeFromFile(path, &pc_generic));
+  ASSERT_EQ(pc_generic.num_points(), 4);
+  ASSERT_EQ(pc_generic.NumNamedAttributes(GeometryAttribute::GENERIC), 2);
+
+  const AttributeMetadata *const confidence_metadata =
+      pc_generic.GetAttributeMetadataByStringEntry("name", "confidence");
+  ASSERT_NE(confidence_metadata, nullptr);
+  const PointAttribute *const confidence_att =
+      pc_generic.GetAttributeByUniqueId(confidence_metadata->att_unique_id());
+  ASSERT_NE(confidence_att, nullptr);
+  ASSERT_EQ(confidence_att->data_type(), DT_FLOAT32);
+  ASSERT_EQ(confidence_att->num_components(), 1);
+  const AttributeMetadata *const intensity_metadata =
+      pc_generic.GetAttributeMetadataByStringEntry("name", "intensity");
+  ASSERT_NE(intensity_metadata, nullptr);
+  const PointAttribute *const intensity_att =
+      pc_generic.GetAttributeByUniqueId(intensity_metadata->att_unique_id());
+  ASSERT_NE(intensity_att, nullptr);
+  ASSERT_EQ(intensity_att->data_type(), DT_UINT8);
+
+  const float expected_confidence[] = {0.5f, 0.25f, 1.f, 0.75f};
+  const uint8_t expected_intensity[] = {10, 20, 30, 40};
+  for (PointIndex i(0); i < 4; ++i) {
+    float confidence;
+    confidence_att->GetMappedValue(i, &confidence);
+    ASSERT_EQ(confidence, expected_confidence[i.value()]);
+    uint8_t intensity;
+    intensity_att->GetMappedValue(i, &intensity);
+    ASSERT_EQ(intensity, expected_intensity[i.value()]);
+  }
+}
+
+TEST_F(PlyDecoderTest, TestPlyGenericPropertiesUnsupportedNormals) {
+  // Tests that normal properties that cannot be decoded as normals (here of
+  // type double) are decoded into generic attributes when requested.
+  const std::string path =
+      GetTestFileFullPath("test_generic_double_normals.ply");
+  PointCloud pc;
+  PlyDecoder decoder;
+  DRACO_ASSERT_OK(decoder.DecodeFromFile(path, &pc));
+  ASSERT_EQ(pc.num_attributes(), 1);
+
+  PointCloud pc_generic;
+  decoder.set_decode_generic_properties(true);
+  DRACO_ASSERT_OK(decoder.DecodeFromFile(path, &pc_generic));
+  ASSERT_EQ(pc_generic.NumNamedAttributes(GeometryAttribute::NORMAL), 0);
+  ASSERT_EQ(pc_generic.NumNamedAttributes(GeometryAttribute::GENERIC), 3);
+  for (const char *const name : {"nx", "ny", "nz"}) {
+    const AttributeMetadata *const metadata =
+        pc_generic.GetAttributeMetadataByStringEntry("name", name);
+    ASSERT_NE(metadata, nullptr);
+    const PointAttribute *const att =
+        pc_generic.GetAttributeByUniqueId(metadata->att_unique_id());
+    ASSERT_NE(att, nullptr);
+    ASSERT_EQ(att->data_type(), DT_FLOAT64);
+  }
+}
+
+TEST_F(PlyDecoderTest, TestPlyListVertexProperties) {
+  // Tests that positions, normals and colors defined as list properties are
+  // rejected instead of being read out of bounds.
+  const std::string header =
+      "ply\nformat ascii 1.0\nelement vertex 2\n";
+  const std::string valid_positions =
+      "property float x\nproperty float y\nproperty float z\n";
+  const std::string inputs[] = {
+      header +
+          "property list uchar float x\nproperty float y\n"
+          "property float z\nend_header\n3 0 0 0 0 0\n1 1 1 1\n",
+      header + valid_positions +
+          "property float nx\nproperty float ny\n"
+          "property list uchar float nz\nend_header\n"
+          "0 0 0 0 0 2 0 1\n1 1 1 0 0 1 1\n",
+      header + valid_positions +
+          "property list uchar uchar red\nend_header\n"
+          "0 0 0 3 255 0 0\n1 1 1 1 255\n"};
+  for (const std::string &data : inputs) {
+    DecoderBuffer buffer;
+    buffer.Init(data.data(), data.size());
+    PointCloud pc;
+    PlyDecoder decoder;
+    const Status status = decoder.DecodeFromBuffer(&buffer, &pc);
+    ASSERT_FALSE(status.ok());
+    ASSERT_EQ(status.code(), Status::INVALID_PARAMETER);
+  }
+}
+
 TEST_F(PlyDecoderTest, TestPlyDecodingAll) {
   // test if we can read all ply that are currently in test folder.
   test_decoding("bun_zipper.ply");
@@ -81,6 +180,7 @@ TEST_F(PlyDecoderTest, TestPlyDecodingAll) {
   test_decoding("test_extra_whitespace.ply");
   test_decoding("test_more_datatypes.ply");
   test_decoding("test_pos_color_ascii.ply");
+  test_decoding("test_pos_color_big_endian.ply", 224, 114, nullptr);
   test_decoding("int_point_cloud.ply", 0, 16, nullptr);
   // test_decoding("test_pos_color.ply"); // tested
   test_decoding("cube_quads.ply");
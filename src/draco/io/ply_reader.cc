@@ -57,13 +57,10 @@ Status PlyReader::Read(DecoderBuffer *buffer) {
   if (version != "1.0") {
     return Status(Status::UNSUPPORTED_VERSION, "Unsupported PLY version");
   }
-  if (format == "binary_big_endian") {
-    return Status(Status::UNSUPPORTED_VERSION,
-                  "Unsupported format. Currently we support only ascii and"
-                  " binary_little_endian format.");
-  }
   if (format == "ascii") {
     format_ = kAscii;
+  } else if (format == "binary_big_endian") {
+    format_ = kBigEndian;
   } else {
     format_ = kLittleEndian;
   }
@@ -80,7 +77,8 @@ Status PlyReader::ParseHeader(DecoderBuffer *buffer) {
     if (end) {
       break;
     }
-    if (ParseElement(buffer)) {
+    DRACO_ASSIGN_OR_RETURN(bool element_parsed, ParseElement(buffer));
+    if (element_parsed) {
       continue;
     }
     DRACO_ASSIGN_OR_RETURN(bool property_parsed, ParseProperty(buffer));
@@ -106,7 +104,7 @@ StatusOr<bool> PlyReader::ParseEndHeader(DecoderBuffer *buffer) {
   return true;
 }
 
-bool PlyReader::ParseElement(DecoderBuffer *buffer) {
+StatusOr<bool> PlyReader::ParseElement(DecoderBuffer *buffer) {
   DecoderBuffer line_buffer(*buffer);
   std::string line;
   parser::ParseLine(&line_buffer, &line);
@@ -121,6 +119,11 @@ bool PlyReader::ParseElement(DecoderBuffer *buffer) {
   } else {
     return false;
   }
+  // Each entry takes at least one byte of the input data. This also prevents
+  // excessive memory allocations for corrupted element counts.
+  if (count < 0 || count > line_buffer.remaining_size()) {
+    return Status(Status::INVALID_PARAMETER, "Invalid element count");
+  }
   element_index_[element_name] = static_cast<uint32_t>(elements_.size());
   elements_.emplace_back(PlyElement(element_name, count));
   *buffer = line_buffer;
@@ -173,7 +176,7 @@ StatusOr<bool> PlyReader::ParseProperty(DecoderBuffer *buffer) {
 
 bool PlyReader::ParsePropertiesData(DecoderBuffer *buffer) {
   for (int i = 0; i < static_cast<int>(elements_.size()); ++i) {
-    if (format_ == kLittleEndian) {
+    if (format_ == kLittleEndian || format_ == kBigEndian) {
       if (!ParseElementData(buffer, i)) {
         return false;
       }
@@ -188,13 +191,25 @@ bool PlyReader::ParsePropertiesData(DecoderBuffer *buffer) {
 
 bool PlyReader::ParseElementData(DecoderBuffer *buffer, int element_index) {
   PlyElement &element = elements_[element_index];
+  // Values are stored in the little endian order so binary big endian data
+  // need to have their bytes swapped.
+  const bool swap_bytes = format_ == kBigEndian;
   for (int entry = 0; entry < element.num_entries(); ++entry) {
     for (int i = 0; i < element.num_properties(); ++i) {
       PlyProperty &prop = element.property(i);
+      const size_t data_offset = prop.data_.size();
       if (prop.is_list()) {
         // Parse the number of entries for the list element.
         int64_t num_entries = 0;
-        buffer->Decode(&num_entries, prop.list_data_type_num_bytes());
+        if (!buffer->Decode(&num_entries, prop.list_data_type_num_bytes())) {
+          return false;
+        }
+        if (swap_bytes) {
+          SwapBytes(&num_entries, prop.list_data_type_num_bytes());
+        }
+        if (num_entries < 0) {
+          return false;
+        }
         // Store offset to the main data entry.
         prop.list_data_.push_back(prop.data_.size() /
                                   prop.data_type_num_bytes_);
@@ -203,15 +218,27 @@ bool PlyReader::ParseElementData(DecoderBuffer *buffer, int element_index) {
         // Read and store the actual property data
         const int64_t num_bytes_to_read =
             prop.data_type_num_bytes() * num_entries;
+        if (num_bytes_to_read > buffer->remaining_size()) {
+          return false;
+        }
         prop.data_.insert(prop.data_.end(), buffer->data_head(),
                           buffer->data_head() + num_bytes_to_read);
         buffer->Advance(num_bytes_to_read);
       } else {
         // Non-list property
+        if (prop.data_type_num_bytes() > buffer->remaining_size()) {
+          return false;
+        }
         prop.data_.insert(prop.data_.end(), buffer->data_head(),
                           buffer->data_head() + prop.data_type_num_bytes());
         buffer->Advance(prop.data_type_num_bytes());
       }
+      if (swap_bytes) {
+        for (size_t offset = data_offset; offset < prop.data_.size();
+             offset += prop.data_type_num_bytes()) {
+          SwapBytes(prop.data_.data() + offset, prop.data_type_num_bytes());
+        }
+      }
     }
   }
   return true;
@@ -282,6 +309,11 @@ std::vector<std::string> PlyReader::SplitWords(const std::string &line) {
   return output;
 }
 
+void PlyReader::SwapBytes(void *data, int num_bytes) {
+  uint8_t *const bytes = static_cast<uint8_t *>(data);
+  std::reverse(bytes, bytes + num_bytes);
+}
+
 DataType PlyReader::GetDataTypeFromString(const std::string &name) const {
   if (name == "char" || name == "int8") {
     return DT_INT8;
@@ -15,8 +15,9 @@
 // File contains helper classes used for parsing of PLY files. The classes are
 // used by the PlyDecoder (ply_decoder.h) to read a point cloud or mesh from a
 // source PLY file.
-// TODO(ostava): Currently, we support only binary PLYs encoded in the little
-// endian format ("format binary_little_endian 1.0").
+// Supported formats are "ascii", "binary_little_endian" and
+// "binary_big_endian" (version 1.0). Binary data is always stored in the
+// little endian order in the parsed properties.
 
 #ifndef DRACO_IO_PLY_READER_H_
 #define DRACO_IO_PLY_READER_H_
@@ -131,11 +132,11 @@ class PlyReader {
   }
 
  private:
-  enum Format { kLittleEndian = 0, kAscii };
+  enum Format { kLittleEndian = 0, kAscii, kBigEndian };
 
   Status ParseHeader(DecoderBuffer *buffer);
   StatusOr<bool> ParseEndHeader(DecoderBuffer *buffer);
-  bool ParseElement(DecoderBuffer *buffer);
+  StatusOr<bool> ParseElement(DecoderBuffer *buffer);
   StatusOr<bool> ParseProperty(DecoderBuffer *buffer);
   bool ParsePropertiesData(DecoderBuffer *buffer);
   bool ParseElementData(DecoderBuffer *buffer, int element_index);
@@ -143,6 +144,8 @@ class PlyReader {
 
   // Splits |line| by whitespace characters.
   std::vector<std::string> SplitWords(const std::string &line);
+  // Reverses the order of |num_bytes| bytes stored at |data|.
+  static void SwapBytes(void *data, int num_bytes);
   DataType GetDataTypeFromString(const std::string &name) const;
 
   std::vector<PlyElement> elements_;
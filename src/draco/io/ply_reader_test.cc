@@ -140,4 +140,76 @@ TEST_F(PlyReaderTest, TestReaderMoreDataTypes) {
   }
 }
 
+TEST_F(PlyReaderTest, TestReaderBigEndian) {
+  // Tests that binary big endian data is parsed into the same values as the
+  // equivalent little endian file.
+  const std::vector<char> data = ReadPlyFile("test_pos_color.ply");
+  ASSERT_NE(data.size(), 0u);
+  DecoderBuffer buf;
+  buf.Init(data.data(), data.size());
+  PlyReader reader;
+  DRACO_ASSERT_OK(reader.Read(&buf));
+
+  const std::vector<char> data_be =
+      ReadPlyFile("test_pos_color_big_endian.ply");
+  ASSERT_NE(data_be.size(), 0u);
+  buf.Init(data_be.data(), data_be.size());
+  PlyReader reader_be;
+  DRACO_ASSERT_OK(reader_be.Read(&buf));
+  ASSERT_EQ(reader.num_elements(), reader_be.num_elements());
+
+  for (int e = 0; e < reader.num_elements(); ++e) {
+    const PlyElement &element = reader.element(e);
+    const PlyElement &element_be = reader_be.element(e);
+    ASSERT_EQ(element.num_entries(), element_be.num_entries());
+    ASSERT_EQ(element.num_properties(), element_be.num_properties());
+    for (int p = 0; p < element.num_properties(); ++p) {
+      const PlyProperty &prop = element.property(p);
+      const PlyProperty &prop_be = element_be.property(p);
+      ASSERT_EQ(prop.name(), prop_be.name());
+      PlyPropertyReader<double> prop_reader(&prop);
+      PlyPropertyReader<double> prop_reader_be(&prop_be);
+      if (prop.is_list()) {
+        for (int i = 0; i < element.num_entries(); ++i) {
+          ASSERT_EQ(prop.GetListEntryNumValues(i),
+                    prop_be.GetListEntryNumValues(i));
+          const int64_t offset = prop.GetListEntryOffset(i);
+          const int64_t offset_be = prop_be.GetListEntryOffset(i);
+          for (int v = 0; v < prop.GetListEntryNumValues(i); ++v) {
+            ASSERT_EQ(prop_reader.ReadValue(static_cast<int>(offset + v)),
+                      prop_reader_be.ReadValue(
+                          static_cast<int>(offset_be + v)));
+          }
+        }
+      } else {
+        for (int i = 0; i < element.num_entries(); ++i) {
+          ASSERT_EQ(prop_reader.ReadValue(i), prop_reader_be.ReadValue(i));
+        }
+      }
+    }
+  }
+}
+
+TEST_F(PlyReaderTest, TestReaderTruncatedData) {
+  // Tests that the reader fails gracefully when the binary data is truncated.
+  const std::vector<char> data = ReadPlyFile("test_pos_color.ply");
+  ASSERT_NE(data.size(), 0u);
+  for (const size_t size : {data.size() - 1, data.size() / 2}) {
+    DecoderBuffer buf;
+    buf.Init(data.data(), size);
+    PlyReader reader;
+    ASSERT_FALSE(reader.Read(&buf).ok());
+  }
+}
+
+TEST_F(PlyReaderTest, TestReaderInvalidElementCount) {
+  const std::string data =
+      "ply\nformat ascii 1.0\nelement vertex -1\nproperty float x\n"
+      "end_header\n";
+  DecoderBuffer buf;
+  buf.Init(data.data(), data.size());
+  PlyReader reader;
+  ASSERT_FALSE(reader.Read(&buf).ok());
+}
+
 }  // namespace draco
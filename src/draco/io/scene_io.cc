@@ -20,8 +20,10 @@
 #include "draco/io/file_utils.h"
 #include "draco/io/gltf_decoder.h"
 #include "draco/io/gltf_encoder.h"
+#include "draco/io/mesh_io.h"
 #include "draco/io/obj_encoder.h"
 #include "draco/io/ply_encoder.h"
+#include "draco/scene/scene_utils.h"
 
 namespace draco {
 
@@ -52,6 +54,13 @@ StatusOr<std::unique_ptr<Scene>> ReadSceneFromFile(
 
 StatusOr<std::unique_ptr<Scene>> ReadSceneFromFile(
     const std::string &file_name, std::vector<std::string> *scene_files) {
+  Options options;
+  return ReadSceneFromFile(file_name, options, scene_files);
+}
+
+StatusOr<std::unique_ptr<Scene>> ReadSceneFromFile(
+    const std::string &file_name, const Options &options,
+    std::vector<std::string> *scene_files) {
   std::unique_ptr<Scene> scene(new Scene());
   switch (GetSceneFileFormat(file_name)) {
     case GLTF: {
@@ -61,6 +70,19 @@ StatusOr<std::unique_ptr<Scene>> ReadSceneFromFile(
     case USD: {
       return Status(Status::DRACO_ERROR, "USD is not supported yet.");
     }
+    case OBJ:
+    case PLY: {
+      // Read the file as a mesh and convert it to a scene with a single node.
+      // Unlike in ReadMeshFromFile(), materials are loaded by default.
+      Options mesh_options = options;
+      if (!mesh_options.IsOptionSet("load_materials")) {
+        mesh_options.SetBool("load_materials", true);
+      }
+      DRACO_ASSIGN_OR_RETURN(
+          std::unique_ptr<Mesh> mesh,
+          ReadMeshFromFile(file_name, mesh_options, scene_files));
+      return SceneUtils::MeshToScene(std::move(mesh));
+    }
     default: {
       return Status(Status::DRACO_ERROR, "Unknown input file format.");
     }
@@ -82,6 +104,7 @@ Status WriteSceneToFile(const std::string &file_name, const Scene &scene,
   switch (format) {
     case GLTF: {
       GltfEncoder encoder;
+      encoder.set_optimize_for_gpu(options.GetBool("optimize_for_gpu"));
       if (!encoder.EncodeToFile(scene, file_name, folder_path)) {
         return Status(Status::DRACO_ERROR, "Failed to encode the scene.");
       }
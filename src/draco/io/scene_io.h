@@ -27,7 +27,9 @@
 
 namespace draco {
 
-// Reads a scene from a file. Currently only GLTF 2.0 scene files are supported.
+// Reads a scene from a file. Currently GLTF 2.0 scene files are supported, and
+// OBJ and PLY files are read as a mesh that is placed in a single scene node.
+// Materials of OBJ files are loaded into the material library of the scene.
 // The second form returns the files associated with the scene via the
 // |scene_files| argument.
 StatusOr<std::unique_ptr<Scene>> ReadSceneFromFile(
@@ -35,6 +37,15 @@ StatusOr<std::unique_ptr<Scene>> ReadSceneFromFile(
 StatusOr<std::unique_ptr<Scene>> ReadSceneFromFile(
     const std::string &file_name, std::vector<std::string> *scene_files);
 
+// Reads a scene from a file. Reading of OBJ and PLY files is configured with
+// |options|, which are passed to ReadMeshFromFile(). Unlike in
+// ReadMeshFromFile(), the load_materials option defaults to true. The options
+// are ignored for GLTF files. The files associated with the scene are returned
+// via the |scene_files| argument, which may be nullptr.
+StatusOr<std::unique_ptr<Scene>> ReadSceneFromFile(
+    const std::string &file_name, const Options &options,
+    std::vector<std::string> *scene_files);
+
 // Writes a scene into a file.
 Status WriteSceneToFile(const std::string &file_name, const Scene &scene);
 
@@ -46,6 +57,12 @@ Status WriteSceneToFile(const std::string &file_name, const Scene &scene);
 //                             interpolation while exporting to USD
 //                             (default = false)
 //
+//   optimize_for_gpu=<bool> - reorders faces and points of the meshes for
+//                             better GPU vertex cache utilization while
+//                             exporting to glTF; meshes with Draco
+//                             compression are not reordered
+//                             (default = false)
+//
 Status WriteSceneToFile(const std::string &file_name, const Scene &scene,
                         const Options &options);
 
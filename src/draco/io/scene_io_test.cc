@@ -16,11 +16,13 @@
 
 #include <string>
 #include <utility>
+#include <vector>
 
 #ifdef DRACO_TRANSCODER_SUPPORTED
 #include "draco/core/draco_test_utils.h"
 #include "draco/io/file_utils.h"
 #include "draco/io/mesh_io.h"
+#include "draco/mesh/mesh_vertex_cache_optimizer.h"
 
 namespace {
 
@@ -48,6 +50,48 @@ TEST(SceneTest, TestSceneIO) {
             0);
 }
 
+TEST(SceneTest, TestReadSceneFromObj) {
+  // Tests that an OBJ file is read as a scene with one mesh per material.
+  const std::string file_name =
+      draco::GetTestFileFullPath("textured_materials.obj");
+  std::vector<std::string> scene_files;
+  DRACO_ASSIGN_OR_ASSERT(std::unique_ptr<draco::Scene> scene,
+                         draco::ReadSceneFromFile(file_name, &scene_files));
+  ASSERT_EQ(scene->NumNodes(), 1);
+  ASSERT_EQ(scene->NumRootNodes(), 1);
+  ASSERT_EQ(scene->NumMeshes(), 2);
+  ASSERT_EQ(scene->GetMaterialLibrary().NumMaterials(), 2);
+  ASSERT_EQ(scene->GetMaterialLibrary().GetTextureLibrary().NumTextures(), 2);
+  ASSERT_EQ(scene_files.size(), 4);
+}
+
+TEST(SceneTest, TestReadSceneFromObjWithoutMaterials) {
+  // Tests that loading of OBJ materials can be disabled with options.
+  const std::string file_name =
+      draco::GetTestFileFullPath("textured_materials.obj");
+  draco::Options options;
+  options.SetBool("load_materials", false);
+  DRACO_ASSIGN_OR_ASSERT(
+      std::unique_ptr<draco::Scene> scene,
+      draco::ReadSceneFromFile(file_name, options, nullptr));
+  ASSERT_EQ(scene->NumNodes(), 1);
+  ASSERT_EQ(scene->NumMeshes(), 1);
+  // A default material is created for the scene.
+  ASSERT_EQ(scene->GetMaterialLibrary().NumMaterials(), 1);
+  ASSERT_EQ(scene->GetMaterialLibrary().GetTextureLibrary().NumTextures(), 0);
+}
+
+TEST(SceneTest, TestReadSceneFromPly) {
+  // Tests that a PLY file is read as a scene with a single mesh.
+  const std::string file_name =
+      draco::GetTestFileFullPath("test_pos_color.ply");
+  DRACO_ASSIGN_OR_ASSERT(std::unique_ptr<draco::Scene> scene,
+                         draco::ReadSceneFromFile(file_name));
+  ASSERT_EQ(scene->NumNodes(), 1);
+  ASSERT_EQ(scene->NumMeshes(), 1);
+  ASSERT_EQ(scene->GetMesh(draco::MeshIndex(0)).num_faces(), 224);
+}
+
 TEST(SceneTest, TestSaveToPly) {
   // A simple test that verifies that a loaded scene can be stored in a PLY file
   // format.
@@ -82,5 +126,37 @@ TEST(SceneTest, TestSaveToObj) {
   ASSERT_NE(mesh, nullptr);
 }
 
+TEST(SceneTest, TestSaveToGltfOptimizedForGpu) {
+  // Tests that the optimize_for_gpu option reorders faces of the saved meshes
+  // for better vertex cache utilization.
+  const std::string file_name =
+      draco::GetTestFileFullPath("CesiumMilkTruck/glTF/CesiumMilkTruck.gltf");
+  DRACO_ASSIGN_OR_ASSERT(std::unique_ptr<draco::Scene> scene,
+                         draco::ReadSceneFromFile(file_name));
+
+  const std::string out_file_name =
+      draco::GetTestTempFileFullPath("out_scene_optimized.glb");
+  draco::Options options;
+  options.SetBool("optimize_for_gpu", true);
+  DRACO_ASSERT_OK(draco::WriteSceneToFile(out_file_name, *scene, options));
+  DRACO_ASSIGN_OR_ASSERT(std::unique_ptr<draco::Scene> optimized_scene,
+                         draco::ReadSceneFromFile(out_file_name));
+  ASSERT_EQ(optimized_scene->NumMeshes(), scene->NumMeshes());
+
+  const int kCacheSize = 16;
+  float acmr = 0.f;
+  float optimized_acmr = 0.f;
+  for (draco::MeshIndex i(0); i < scene->NumMeshes(); ++i) {
+    ASSERT_EQ(optimized_scene->GetMesh(i).num_faces(),
+              scene->GetMesh(i).num_faces());
+    acmr += draco::MeshVertexCacheOptimizer::ComputeAverageCacheMissRatio(
+        scene->GetMesh(i), kCacheSize);
+    optimized_acmr +=
+        draco::MeshVertexCacheOptimizer::ComputeAverageCacheMissRatio(
+            optimized_scene->GetMesh(i), kCacheSize);
+  }
+  ASSERT_LT(optimized_acmr, acmr);
+}
+
 }  // namespace
 #endif  // DRACO_TRANSCODER_SUPPORTED
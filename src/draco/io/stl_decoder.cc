@@ -37,6 +37,9 @@ StatusOr<std::unique_ptr<Mesh>> StlDecoder::DecodeFromFile(
 
 StatusOr<std::unique_ptr<Mesh>> StlDecoder::DecodeFromBuffer(
     DecoderBuffer *buffer) {
+  if (buffer->remaining_size() < 84) {
+    return Status(Status::IO_ERROR, "STL header is truncated.");
+  }
   if (!strncmp(buffer->data_head(), "solid ", 6)) {
     return Status(Status::IO_ERROR,
                   "Currently only binary STL files are supported.");
@@ -44,6 +47,10 @@ StatusOr<std::unique_ptr<Mesh>> StlDecoder::DecodeFromBuffer(
   buffer->Advance(80);
   uint32_t face_count;
   buffer->Decode(&face_count, 4);
+  // Each face is stored in 50 bytes.
+  if (face_count > buffer->remaining_size() / 50) {
+    return Status(Status::IO_ERROR, "STL face data is truncated.");
+  }
 
   TriangleSoupMeshBuilder builder;
   builder.Start(face_count);
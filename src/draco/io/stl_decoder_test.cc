@@ -18,6 +18,7 @@
 
 #include "draco/core/draco_test_base.h"
 #include "draco/core/draco_test_utils.h"
+#include "draco/io/file_utils.h"
 
 namespace draco {
 
@@ -46,4 +47,17 @@ TEST_F(StlDecoderTest, TestStlDecoding) {
   test_decoding_should_fail("STL/test_sphere_ascii.stl");
 }
 
+TEST_F(StlDecoderTest, TestTruncatedStlDecoding) {
+  // Tests that decoding of truncated STL data fails gracefully.
+  std::vector<char> data;
+  ASSERT_TRUE(
+      ReadFileToBuffer(GetTestFileFullPath("STL/test_sphere.stl"), &data));
+  for (const size_t size : {size_t(0), size_t(40), data.size() / 2}) {
+    DecoderBuffer buffer;
+    buffer.Init(data.data(), size);
+    StlDecoder decoder;
+    ASSERT_FALSE(decoder.DecodeFromBuffer(&buffer).ok());
+  }
+}
+
 }  // namespace draco
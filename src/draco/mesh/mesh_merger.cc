@@ -0,0 +1,156 @@
+// Copyright 2026 The Draco Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+#include "draco/mesh/mesh_merger.h"
+
+#include <utility>
+
+namespace draco {
+
+Status MeshMerger::Append(const Mesh &src_mesh, Mesh *dst_mesh) {
+  return Append(src_mesh, dst_mesh, MeshMergerOptions());
+}
+
+Status MeshMerger::Append(const Mesh &src_mesh, Mesh *dst_mesh,
+                          const MeshMergerOptions &options) {
+  DRACO_RETURN_IF_ERROR(AppendInternal(src_mesh, dst_mesh));
+  if (options.weld_points) {
+    DRACO_RETURN_IF_ERROR(WeldPoints(dst_mesh));
+  }
+  return OkStatus();
+}
+
+StatusOr<std::unique_ptr<Mesh>> MeshMerger::Merge(
+    const std::vector<const Mesh *> &meshes) {
+  return Merge(meshes, MeshMergerOptions());
+}
+
+StatusOr<std::unique_ptr<Mesh>> MeshMerger::Merge(
+    const std::vector<const Mesh *> &meshes,
+    const MeshMergerOptions &options) {
+  std::unique_ptr<Mesh> merged_mesh(new Mesh());
+  for (const Mesh *const mesh : meshes) {
+    DRACO_RETURN_IF_ERROR(AppendInternal(*mesh, merged_mesh.get()));
+  }
+  // Weld the points only once after all meshes are merged.
+  if (options.weld_points) {
+    DRACO_RETURN_IF_ERROR(WeldPoints(merged_mesh.get()));
+  }
+  return std::move(merged_mesh);
+}
+
+Status MeshMerger::AppendInternal(const Mesh &src_mesh, Mesh *dst_mesh) {
+  if (dst_mesh->num_points() == 0 && dst_mesh->num_faces() == 0 &&
+      dst_mesh->num_attributes() == 0) {
+    // Add empty attributes with the same properties as the attributes of the
+    // source mesh.
+    for (int i = 0; i < src_mesh.num_attributes(); ++i) {
+      const PointAttribute *const src_att = src_mesh.attribute(i);
+      std::unique_ptr<PointAttribute> att(new PointAttribute());
+      att->Init(src_att->attribute_type(), src_att->num_components(),
+                src_att->data_type(), src_att->normalized(), 0);
+#ifdef DRACO_TRANSCODER_SUPPORTED
+      att->set_name(src_att->name());
+#endif
+      dst_mesh->AddAttribute(std::move(att));
+    }
+  }
+  DRACO_RETURN_IF_ERROR(CheckAttributesCompatible(src_mesh, *dst_mesh));
+
+  const PointIndex::ValueType dst_num_points = dst_mesh->num_points();
+  const PointIndex::ValueType src_num_points = src_mesh.num_points();
+  const PointIndex::ValueType num_points = dst_num_points + src_num_points;
+
+  for (int i = 0; i < src_mesh.num_attributes(); ++i) {
+    const PointAttribute *const src_att = src_mesh.attribute(i);
+    PointAttribute *const dst_att = dst_mesh->attribute(i);
+    const AttributeValueIndex::ValueType dst_num_values = dst_att->size();
+
+    // Append all attribute values.
+    dst_att->Resize(dst_num_values + src_att->size());
+    for (AttributeValueIndex avi(0); avi < src_att->size(); ++avi) {
+      dst_att->SetAttributeValue(
+          AttributeValueIndex(dst_num_values + avi.value()),
+          src_att->GetAddress(avi));
+    }
+
+    // Update the point to attribute value mapping. The identity mapping can be
+    // kept only when both attributes have one value per point.
+    if (dst_att->is_mapping_identity() && src_att->is_mapping_identity() &&
+        dst_num_values == dst_num_points && src_att->size() == src_num_points) {
+      continue;
+    }
+    const bool was_mapping_identity = dst_att->is_mapping_identity();
+    dst_att->SetExplicitMapping(num_points);
+    if (was_mapping_identity) {
+      for (PointIndex pi(0); pi < dst_num_points; ++pi) {
+        dst_att->SetPointMapEntry(pi, AttributeValueIndex(pi.value()));
+      }
+    }
+    for (PointIndex pi(0); pi < src_num_points; ++pi) {
+      dst_att->SetPointMapEntry(
+          PointIndex(dst_num_points + pi.value()),
+          AttributeValueIndex(dst_num_values +
+                              src_att->mapped_index(pi).value()));
+    }
+  }
+  dst_mesh->set_num_points(num_points);
+
+  // Append faces with point indices offset by the number of existing points.
+  for (FaceIndex fi(0); fi < src_mesh.num_faces(); ++fi) {
+    Mesh::Face face = src_mesh.face(fi);
+    for (int c = 0; c < 3; ++c) {
+      face[c] = PointIndex(dst_num_points + face[c].value());
+    }
+    dst_mesh->AddFace(face);
+  }
+  return OkStatus();
+}
+
+Status MeshMerger::WeldPoints(Mesh *mesh) {
+#if defined(DRACO_ATTRIBUTE_VALUES_DEDUPLICATION_SUPPORTED) && \
+    defined(DRACO_ATTRIBUTE_INDICES_DEDUPLICATION_SUPPORTED)
+  if (!mesh->DeduplicateAttributeValues()) {
+    return Status(Status::DRACO_ERROR,
+                  "Could not deduplicate attribute values.");
+  }
+  mesh->DeduplicatePointIds();
+  return OkStatus();
+#else
+  return Status(Status::UNSUPPORTED_FEATURE,
+                "Welding of points requires attribute deduplication support.");
+#endif
+}
+
+Status MeshMerger::CheckAttributesCompatible(const Mesh &src_mesh,
+                                             const Mesh &dst_mesh) {
+  if (src_mesh.num_attributes() != dst_mesh.num_attributes()) {
+    return Status(Status::INVALID_PARAMETER,
+                  "Meshes have different number of attributes.");
+  }
+  for (int i = 0; i < src_mesh.num_attributes(); ++i) {
+    const PointAttribute *const src_att = src_mesh.attribute(i);
+    const PointAttribute *const dst_att = dst_mesh.attribute(i);
+    if (src_att->attribute_type() != dst_att->attribute_type() ||
+        src_att->data_type() != dst_att->data_type() ||
+        src_att->num_components() != dst_att->num_components() ||
+        src_att->normalized() != dst_att->normalized()) {
+      return Status(Status::INVALID_PARAMETER,
+                    "Meshes have incompatible attributes.");
+    }
+  }
+  return OkStatus();
+}
+
+}  // namespace draco
@@ -0,0 +1,74 @@
+// Copyright 2026 The Draco Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+#ifndef DRACO_MESH_MESH_MERGER_H_
+#define DRACO_MESH_MESH_MERGER_H_
+
+#include <memory>
+#include <vector>
+
+#include "draco/core/status.h"
+#include "draco/core/status_or.h"
+#include "draco/draco_features.h"
+#include "draco/mesh/mesh.h"
+
+namespace draco {
+
+// Options used by the MeshMerger class.
+struct MeshMergerOptions {
+  // If true, points of the resulting mesh that have the same values for all
+  // attributes are welded together. Welding is performed on the whole
+  // resulting mesh, i.e., it also affects the points that were present in the
+  // destination mesh before the merge.
+  bool weld_points = false;
+};
+
+// Tool that can be used to combine multiple draco::Mesh instances into a
+// single mesh. All merged meshes must have the same set of attributes, i.e.,
+// the attributes must be stored in the same order and they must have the same
+// type, data type, number of components and normalization.
+//
+// By default, points of the merged meshes are not welded together. See
+// MeshMergerOptions::weld_points.
+//
+// Attribute values are copied as they are. This means that values of the
+// MATERIAL attribute are not remapped and the material libraries, mesh
+// features and metadata of the merged meshes are not combined.
+class MeshMerger {
+ public:
+  // Appends all faces, points and attribute values of |src_mesh| to
+  // |dst_mesh|. If |dst_mesh| is empty (it has no points, faces and
+  // attributes), the attributes of |src_mesh| are added to it first.
+  static Status Append(const Mesh &src_mesh, Mesh *dst_mesh);
+  static Status Append(const Mesh &src_mesh, Mesh *dst_mesh,
+                       const MeshMergerOptions &options);
+
+  // Returns a new mesh containing all faces, points and attribute values of
+  // the input |meshes|.
+  static StatusOr<std::unique_ptr<Mesh>> Merge(
+      const std::vector<const Mesh *> &meshes);
+  static StatusOr<std::unique_ptr<Mesh>> Merge(
+      const std::vector<const Mesh *> &meshes,
+      const MeshMergerOptions &options);
+
+ private:
+  static Status AppendInternal(const Mesh &src_mesh, Mesh *dst_mesh);
+  static Status WeldPoints(Mesh *mesh);
+  static Status CheckAttributesCompatible(const Mesh &src_mesh,
+                                          const Mesh &dst_mesh);
+};
+
+}  // namespace draco
+
+#endif  // DRACO_MESH_MESH_MERGER_H_
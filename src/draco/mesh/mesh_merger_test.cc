@@ -0,0 +1,152 @@
+// Copyright 2026 The Draco Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+#include "draco/mesh/mesh_merger.h"
+
+#include "draco/core/draco_test_base.h"
+#include "draco/core/draco_test_utils.h"
+#include "draco/core/vector_d.h"
+
+namespace draco {
+
+class MeshMergerTest : public ::testing::Test {
+ protected:
+  // Verifies that all attribute values of |src_mesh| are present on the
+  // corresponding points of |merged_mesh| starting at |point_offset|.
+  void VerifyMergedPoints(const Mesh &src_mesh, const Mesh &merged_mesh,
+                          PointIndex::ValueType point_offset) {
+    for (int a = 0; a < src_mesh.num_attributes(); ++a) {
+      const PointAttribute *const src_att = src_mesh.attribute(a);
+      const PointAttribute *const att = merged_mesh.attribute(a);
+      const int value_size = src_att->byte_stride();
+      for (PointIndex pi(0); pi < src_mesh.num_points(); ++pi) {
+        const uint8_t *const src_value = src_att->GetAddressOfMappedIndex(pi);
+        const uint8_t *const value = att->GetAddressOfMappedIndex(
+            PointIndex(point_offset + pi.value()));
+        ASSERT_EQ(memcmp(src_value, value, value_size), 0);
+      }
+    }
+  }
+};
+
+TEST_F(MeshMergerTest, TestMergeMeshes) {
+  // Tests merging of two meshes with the same attributes. The attributes of
+  // cube_att.obj use explicit attribute mapping.
+  const std::unique_ptr<Mesh> mesh_0 = ReadMeshFromTestFile("cube_att.obj");
+  ASSERT_NE(mesh_0, nullptr);
+  const std::unique_ptr<Mesh> mesh_1 = ReadMeshFromTestFile("cube_att.obj");
+  ASSERT_NE(mesh_1, nullptr);
+
+  DRACO_ASSIGN_OR_ASSERT(std::unique_ptr<Mesh> merged_mesh,
+                         MeshMerger::Merge({mesh_0.get(), mesh_1.get()}));
+  ASSERT_EQ(merged_mesh->num_attributes(), mesh_0->num_attributes());
+  ASSERT_EQ(merged_mesh->num_points(),
+            mesh_0->num_points() + mesh_1->num_points());
+  ASSERT_EQ(merged_mesh->num_faces(),
+            mesh_0->num_faces() + mesh_1->num_faces());
+  for (FaceIndex fi(0); fi < mesh_1->num_faces(); ++fi) {
+    const Mesh::Face &face =
+        merged_mesh->face(FaceIndex(mesh_0->num_faces() + fi.value()));
+    for (int c = 0; c < 3; ++c) {
+      ASSERT_EQ(face[c].value(),
+                mesh_0->num_points() + mesh_1->face(fi)[c].value());
+    }
+  }
+  VerifyMergedPoints(*mesh_0, *merged_mesh, 0);
+  VerifyMergedPoints(*mesh_1, *merged_mesh, mesh_0->num_points());
+}
+
+TEST_F(MeshMergerTest, TestAppendToMeshWithIdentityMapping) {
+  // Tests that appending a mesh with explicit attribute mapping to a mesh with
+  // identity mapping keeps all attribute values intact.
+  std::unique_ptr<Mesh> dst_mesh(new Mesh());
+  GeometryAttribute pos;
+  pos.Init(GeometryAttribute::POSITION, nullptr, 3, DT_FLOAT32, false,
+           sizeof(float) * 3, 0);
+  const int pos_att_id = dst_mesh->AddAttribute(pos, true, 3);
+  for (AttributeValueIndex avi(0); avi < 3; ++avi) {
+    const Vector3f value(avi.value(), 0.f, 0.f);
+    dst_mesh->attribute(pos_att_id)->SetAttributeValue(avi, value.data());
+  }
+  dst_mesh->set_num_points(3);
+  dst_mesh->AddFace({{PointIndex(0), PointIndex(1), PointIndex(2)}});
+
+  // Create a mesh with shared position values.
+  std::unique_ptr<Mesh> src_mesh(new Mesh());
+  const int src_pos_att_id = src_mesh->AddAttribute(pos, false, 4);
+  PointAttribute *const src_pos_att = src_mesh->attribute(src_pos_att_id);
+  for (AttributeValueIndex avi(0); avi < 4; ++avi) {
+    const Vector3f value(0.f, avi.value(), 0.f);
+    src_pos_att->SetAttributeValue(avi, value.data());
+  }
+  src_mesh->set_num_points(6);
+  src_pos_att->SetExplicitMapping(6);
+  for (PointIndex pi(0); pi < 6; ++pi) {
+    src_pos_att->SetPointMapEntry(pi, AttributeValueIndex(pi.value() % 4));
+  }
+  src_mesh->AddFace({{PointIndex(0), PointIndex(1), PointIndex(2)}});
+  src_mesh->AddFace({{PointIndex(3), PointIndex(4), PointIndex(5)}});
+
+  const std::unique_ptr<Mesh> orig_dst_mesh(new Mesh());
+  DRACO_ASSERT_OK(MeshMerger::Append(*dst_mesh, orig_dst_mesh.get()));
+
+  DRACO_ASSERT_OK(MeshMerger::Append(*src_mesh, dst_mesh.get()));
+  ASSERT_EQ(dst_mesh->num_points(), 9);
+  ASSERT_EQ(dst_mesh->num_faces(), 3);
+  ASSERT_EQ(dst_mesh->attribute(pos_att_id)->size(), 7);
+  VerifyMergedPoints(*orig_dst_mesh, *dst_mesh, 0);
+  VerifyMergedPoints(*src_mesh, *dst_mesh, 3);
+}
+
+TEST_F(MeshMergerTest, TestMergeWithWelding) {
+  // Tests that merging a mesh with itself and welding the points results in a
+  // mesh with the points of the original mesh.
+  const std::unique_ptr<Mesh> mesh = ReadMeshFromTestFile("cube_att.obj");
+  ASSERT_NE(mesh, nullptr);
+
+  MeshMergerOptions options;
+  options.weld_points = true;
+  DRACO_ASSIGN_OR_ASSERT(
+      std::unique_ptr<Mesh> merged_mesh,
+      MeshMerger::Merge({mesh.get(), mesh.get()}, options));
+  ASSERT_EQ(merged_mesh->num_points(), mesh->num_points());
+  ASSERT_EQ(merged_mesh->num_faces(), 2 * mesh->num_faces());
+  for (int a = 0; a < mesh->num_attributes(); ++a) {
+    ASSERT_EQ(merged_mesh->attribute(a)->size(), mesh->attribute(a)->size());
+  }
+  // Both copies of each face must reference the same welded points.
+  for (FaceIndex fi(0); fi < mesh->num_faces(); ++fi) {
+    ASSERT_EQ(merged_mesh->face(fi),
+              merged_mesh->face(FaceIndex(mesh->num_faces() + fi.value())));
+  }
+
+  // Check that appending with welding also welds the points.
+  std::unique_ptr<Mesh> dst_mesh(new Mesh());
+  DRACO_ASSERT_OK(MeshMerger::Append(*mesh, dst_mesh.get()));
+  DRACO_ASSERT_OK(MeshMerger::Append(*mesh, dst_mesh.get(), options));
+  ASSERT_EQ(dst_mesh->num_points(), mesh->num_points());
+  ASSERT_EQ(dst_mesh->num_faces(), 2 * mesh->num_faces());
+}
+
+TEST_F(MeshMergerTest, TestIncompatibleAttributes) {
+  // Tests that meshes with different attributes cannot be merged.
+  const std::unique_ptr<Mesh> mesh_0 = ReadMeshFromTestFile("cube_att.obj");
+  ASSERT_NE(mesh_0, nullptr);
+  const std::unique_ptr<Mesh> mesh_1 = ReadMeshFromTestFile("test_nm.obj");
+  ASSERT_NE(mesh_1, nullptr);
+  ASSERT_NE(mesh_0->num_attributes(), mesh_1->num_attributes());
+  ASSERT_FALSE(MeshMerger::Append(*mesh_1, mesh_0.get()).ok());
+}
+
+}  // namespace draco
@@ -0,0 +1,237 @@
+// Copyright 2026 The Draco Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+#include "draco/mesh/mesh_vertex_cache_optimizer.h"
+
+#include <deque>
+#include <vector>
+
+namespace draco {
+
+Status MeshVertexCacheOptimizer::Optimize(
+    Mesh *mesh, const MeshVertexCacheOptimizerOptions &options) {
+  if (options.cache_size < 3) {
+    return Status(Status::DRACO_ERROR, "Invalid vertex cache size.");
+  }
+  ReorderFaces(mesh, options.cache_size);
+  if (options.reorder_points) {
+    ReorderPoints(mesh);
+  }
+  return OkStatus();
+}
+
+float MeshVertexCacheOptimizer::ComputeAverageCacheMissRatio(const Mesh &mesh,
+                                                             int cache_size) {
+  if (mesh.num_faces() == 0) {
+    return 0.f;
+  }
+  std::deque<PointIndex> cache;
+  std::vector<bool> is_point_in_cache(mesh.num_points(), false);
+  int num_cache_misses = 0;
+  for (FaceIndex f(0); f < mesh.num_faces(); ++f) {
+    const Mesh::Face &face = mesh.face(f);
+    for (int c = 0; c < 3; ++c) {
+      if (is_point_in_cache[face[c].value()]) {
+        continue;
+      }
+      ++num_cache_misses;
+      is_point_in_cache[face[c].value()] = true;
+      cache.push_back(face[c]);
+      if (cache.size() > static_cast<size_t>(cache_size)) {
+        is_point_in_cache[cache.front().value()] = false;
+        cache.pop_front();
+      }
+    }
+  }
+  return static_cast<float>(num_cache_misses) / mesh.num_faces();
+}
+
+void MeshVertexCacheOptimizer::ReorderFaces(Mesh *mesh, int cache_size) {
+  const int num_points = mesh->num_points();
+  const int num_faces = mesh->num_faces();
+
+  // Build the point to face adjacency. Faces incident to point |p| are stored
+  // in |adjacent_faces| between |adjacency_offsets[p]| and
+  // |adjacency_offsets[p + 1]|.
+  std::vector<int> adjacency_offsets(num_points + 1, 0);
+  for (FaceIndex f(0); f < num_faces; ++f) {
+    const Mesh::Face &face = mesh->face(f);
+    for (int c = 0; c < 3; ++c) {
+      ++adjacency_offsets[face[c].value() + 1];
+    }
+  }
+  for (int p = 0; p < num_points; ++p) {
+    adjacency_offsets[p + 1] += adjacency_offsets[p];
+  }
+  std::vector<FaceIndex> adjacent_faces(adjacency_offsets[num_points]);
+  std::vector<int> insert_pos(adjacency_offsets.begin(),
+                              adjacency_offsets.end() - 1);
+  for (FaceIndex f(0); f < num_faces; ++f) {
+    const Mesh::Face &face = mesh->face(f);
+    for (int c = 0; c < 3; ++c) {
+      adjacent_faces[insert_pos[face[c].value()]++] = f;
+    }
+  }
+
+  // Number of incident faces that have not been emitted yet for each point.
+  std::vector<int> num_live_faces(num_points);
+  for (int p = 0; p < num_points; ++p) {
+    num_live_faces[p] = adjacency_offsets[p + 1] - adjacency_offsets[p];
+  }
+  // Time stamp when each point entered the simulated cache.
+  std::vector<int> cache_time_stamps(num_points, 0);
+  std::vector<bool> is_face_emitted(num_faces, false);
+  std::vector<int> dead_end_stack;
+  std::vector<int> candidates;
+  std::vector<Mesh::Face> new_faces;
+  new_faces.reserve(num_faces);
+  int time_stamp = cache_size + 1;
+  int cursor = 0;
+
+  // Returns the next point with live faces when the fanning ended in a dead
+  // end. Recently used points are preferred over an arbitrary unprocessed
+  // point. Returns -1 when all faces have been emitted.
+  const auto skip_dead_end = [&]() -> int {
+    while (!dead_end_stack.empty()) {
+      const int p = dead_end_stack.back();
+      dead_end_stack.pop_back();
+      if (num_live_faces[p] > 0) {
+        return p;
+      }
+    }
+    while (cursor < num_points) {
+      if (num_live_faces[cursor] > 0) {
+        return cursor;
+      }
+      ++cursor;
+    }
+    return -1;
+  };
+
+  int fanning_point = skip_dead_end();
+  while (fanning_point >= 0) {
+    // Emit all remaining faces around the fanning point.
+    candidates.clear();
+    for (int i = adjacency_offsets[fanning_point];
+         i < adjacency_offsets[fanning_point + 1]; ++i) {
+      const FaceIndex f = adjacent_faces[i];
+      if (is_face_emitted[f.value()]) {
+        continue;
+      }
+      is_face_emitted[f.value()] = true;
+      const Mesh::Face &face = mesh->face(f);
+      new_faces.push_back(face);
+      for (int c = 0; c < 3; ++c) {
+        const int p = face[c].value();
+        dead_end_stack.push_back(p);
+        candidates.push_back(p);
+        --num_live_faces[p];
+        if (time_stamp - cache_time_stamps[p] > cache_size) {
+          // The point was not in the cache.
+          cache_time_stamps[p] = time_stamp++;
+        }
+      }
+    }
+
+    // Select the next fanning point among the candidates. Points that will
+    // still be in the cache after all their remaining faces are emitted are
+    // preferred, the oldest of them first.
+    int next_point = -1;
+    int best_priority = -1;
+    for (const int p : candidates) {
+      if (num_live_faces[p] == 0) {
+        continue;
+      }
+      int priority = 0;
+      const int age = time_stamp - cache_time_stamps[p];
+      if (age + 2 * num_live_faces[p] <= cache_size) {
+        priority = age;
+      }
+      if (priority > best_priority) {
+        best_priority = priority;
+        next_point = p;
+      }
+    }
+    if (next_point == -1) {
+      next_point = skip_dead_end();
+    }
+    fanning_point = next_point;
+  }
+
+  for (FaceIndex f(0); f < num_faces; ++f) {
+    mesh->SetFace(f, new_faces[f.value()]);
+  }
+}
+
+void MeshVertexCacheOptimizer::ReorderPoints(Mesh *mesh) {
+  const PointIndex::ValueType num_points = mesh->num_points();
+  // Map from old points to the new ones.
+  IndexTypeVector<PointIndex, PointIndex> point_map(num_points,
+                                                    kInvalidPointIndex);
+  // Map from new points to the old ones.
+  IndexTypeVector<PointIndex, PointIndex> new_to_old_point_map;
+  new_to_old_point_map.reserve(num_points);
+  for (FaceIndex f(0); f < mesh->num_faces(); ++f) {
+    const Mesh::Face &face = mesh->face(f);
+    for (int c = 0; c < 3; ++c) {
+      if (point_map[face[c]] == kInvalidPointIndex) {
+        point_map[face[c]] = PointIndex(new_to_old_point_map.size());
+        new_to_old_point_map.push_back(face[c]);
+      }
+    }
+  }
+  // Points that are not used by any face keep their relative order.
+  for (PointIndex i(0); i < num_points; ++i) {
+    if (point_map[i] == kInvalidPointIndex) {
+      point_map[i] = PointIndex(new_to_old_point_map.size());
+      new_to_old_point_map.push_back(i);
+    }
+  }
+
+  // Go over faces and update their points.
+  for (FaceIndex f(0); f < mesh->num_faces(); ++f) {
+    Mesh::Face face = mesh->face(f);
+    for (int c = 0; c < 3; ++c) {
+      face[c] = point_map[face[c]];
+    }
+    mesh->SetFace(f, face);
+  }
+
+  // Update the attributes.
+  std::vector<AttributeValueIndex> old_point_to_value_map(num_points);
+  for (int a = 0; a < mesh->num_attributes(); ++a) {
+    PointAttribute *const att = mesh->attribute(a);
+    if (att->is_mapping_identity()) {
+      // Permute the attribute values so that the mapping remains identity.
+      PointAttribute old_att;
+      old_att.CopyFrom(*att);
+      for (PointIndex i(0); i < num_points; ++i) {
+        const PointIndex old_point = new_to_old_point_map[i];
+        att->SetAttributeValue(
+            AttributeValueIndex(i.value()),
+            old_att.GetAddress(AttributeValueIndex(old_point.value())));
+      }
+    } else {
+      for (PointIndex i(0); i < num_points; ++i) {
+        old_point_to_value_map[i.value()] = att->mapped_index(i);
+      }
+      for (PointIndex i(0); i < num_points; ++i) {
+        att->SetPointMapEntry(
+            i, old_point_to_value_map[new_to_old_point_map[i].value()]);
+      }
+    }
+  }
+}
+
+}  // namespace draco
@@ -0,0 +1,69 @@
+// Copyright 2026 The Draco Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+#ifndef DRACO_MESH_MESH_VERTEX_CACHE_OPTIMIZER_H_
+#define DRACO_MESH_MESH_VERTEX_CACHE_OPTIMIZER_H_
+
+#include "draco/core/status.h"
+#include "draco/draco_features.h"
+#include "draco/mesh/mesh.h"
+
+namespace draco {
+
+// Options used by the MeshVertexCacheOptimizer class.
+struct MeshVertexCacheOptimizerOptions {
+  // Number of entries of the simulated post-transform vertex cache that the
+  // faces are reordered for.
+  int cache_size = 16;
+
+  // If true, point ids are renumbered in the order in which they are first
+  // referenced by the reordered faces, which improves locality of vertex
+  // fetches. Points that are not referenced by any face are moved to the end.
+  bool reorder_points = true;
+};
+
+// Tool that reorders faces and points of a draco::Mesh to improve its
+// rendering performance on GPUs. Faces are reordered using the vertex cache
+// optimization of the Tipsify algorithm from "Fast Triangle Reordering for
+// Vertex Locality and Reduced Overdraw" by Sander et al. (2007). The overdraw
+// reduction step of the algorithm (sorting of face clusters) is not
+// implemented. Geometry of the mesh is not changed.
+//
+// Note that the optimization is useful mostly for meshes that are stored
+// without Draco compression or with the sequential connectivity encoding, as
+// the edgebreaker decoder defines its own order of faces and points.
+//
+// The optimization does not keep triangles of the same polygon next to each
+// other. Therefore, polygons of meshes decoded with the "added_edges"
+// attribute (see ObjDecoder::set_preserve_polygons()) cannot be reconstructed
+// by ObjEncoder after the optimization.
+class MeshVertexCacheOptimizer {
+ public:
+  // Performs in-place optimization of the input mesh according to the input
+  // options.
+  static Status Optimize(Mesh *mesh,
+                         const MeshVertexCacheOptimizerOptions &options);
+
+  // Returns the average cache miss ratio (number of cache misses per face) of
+  // |mesh| rendered with a FIFO vertex cache of |cache_size| entries.
+  static float ComputeAverageCacheMissRatio(const Mesh &mesh, int cache_size);
+
+ private:
+  static void ReorderFaces(Mesh *mesh, int cache_size);
+  static void ReorderPoints(Mesh *mesh);
+};
+
+}  // namespace draco
+
+#endif  // DRACO_MESH_MESH_VERTEX_CACHE_OPTIMIZER_H_
@@ -0,0 +1,86 @@
+// Copyright 2026 The Draco Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+#include "draco/mesh/mesh_vertex_cache_optimizer.h"
+
+#include "draco/core/draco_test_base.h"
+#include "draco/core/draco_test_utils.h"
+#include "draco/mesh/mesh_are_equivalent.h"
+
+namespace draco {
+
+class MeshVertexCacheOptimizerTest : public ::testing::Test {};
+
+TEST_F(MeshVertexCacheOptimizerTest, TestCacheMissRatioIsReduced) {
+  // Tests that the optimization reduces the number of vertex cache misses
+  // while preserving the geometry of the mesh.
+  const std::unique_ptr<Mesh> mesh = ReadMeshFromTestFile("bun_zipper.ply");
+  ASSERT_NE(mesh, nullptr);
+  const std::unique_ptr<Mesh> orig_mesh =
+      ReadMeshFromTestFile("bun_zipper.ply");
+  ASSERT_NE(orig_mesh, nullptr);
+
+  const int kCacheSize = 16;
+  const float orig_acmr =
+      MeshVertexCacheOptimizer::ComputeAverageCacheMissRatio(*mesh,
+                                                             kCacheSize);
+  MeshVertexCacheOptimizerOptions options;
+  options.cache_size = kCacheSize;
+  DRACO_ASSERT_OK(MeshVertexCacheOptimizer::Optimize(mesh.get(), options));
+  const float acmr =
+      MeshVertexCacheOptimizer::ComputeAverageCacheMissRatio(*mesh,
+                                                             kCacheSize);
+  ASSERT_LT(acmr, orig_acmr);
+  // Tipsify should get well below one cache miss per face on a regular mesh.
+  ASSERT_LT(acmr, 1.f);
+
+  ASSERT_EQ(mesh->num_faces(), orig_mesh->num_faces());
+  ASSERT_EQ(mesh->num_points(), orig_mesh->num_points());
+  MeshAreEquivalent equiv;
+  ASSERT_TRUE(equiv(*mesh, *orig_mesh));
+}
+
+TEST_F(MeshVertexCacheOptimizerTest, TestPointsAreReordered) {
+  // Tests that points are renumbered in the order of their first use. The
+  // input mesh uses explicit attribute mapping.
+  const std::unique_ptr<Mesh> mesh = ReadMeshFromTestFile("cube_att.obj");
+  ASSERT_NE(mesh, nullptr);
+  const std::unique_ptr<Mesh> orig_mesh = ReadMeshFromTestFile("cube_att.obj");
+  ASSERT_NE(orig_mesh, nullptr);
+
+  MeshVertexCacheOptimizerOptions options;
+  DRACO_ASSERT_OK(MeshVertexCacheOptimizer::Optimize(mesh.get(), options));
+  PointIndex::ValueType next_point = 0;
+  for (FaceIndex f(0); f < mesh->num_faces(); ++f) {
+    for (int c = 0; c < 3; ++c) {
+      const PointIndex::ValueType p = mesh->face(f)[c].value();
+      ASSERT_LE(p, next_point);
+      if (p == next_point) {
+        ++next_point;
+      }
+    }
+  }
+  MeshAreEquivalent equiv;
+  ASSERT_TRUE(equiv(*mesh, *orig_mesh));
+}
+
+TEST_F(MeshVertexCacheOptimizerTest, TestInvalidCacheSize) {
+  const std::unique_ptr<Mesh> mesh = ReadMeshFromTestFile("cube_att.obj");
+  ASSERT_NE(mesh, nullptr);
+  MeshVertexCacheOptimizerOptions options;
+  options.cache_size = 2;
+  ASSERT_FALSE(MeshVertexCacheOptimizer::Optimize(mesh.get(), options).ok());
+}
+
+}  // namespace draco
@@ -29,7 +29,7 @@ void Usage() {
   printf("Usage: draco_transcoder [options] -i input -o output\n\n");
   printf("Main options:\n");
   printf("  -h | -?         show help.\n");
-  printf("  -i <input>      input file name.\n");
+  printf("  -i <input>      input file name (.gltf, .glb, .obj or .ply).\n");
   printf("  -o <output>     output file name.\n");
   printf("  -qp <value>     quantization bits for the position attribute, ");
   printf("default=11.\n");
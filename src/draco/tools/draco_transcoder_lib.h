@@ -37,6 +37,8 @@ struct DracoTranscodingOptions {
 
 // Class that supports input of glTF (and some simple USD) files, encodes
 // them with Draco compression, and outputs glTF Draco compressed files.
+// OBJ and PLY files are also accepted as input. They are read as a scene with
+// a single node, see ReadSceneFromFile(). Materials of OBJ files are loaded.
 //
 // glTF supported extensions:
 //  Input and Output:
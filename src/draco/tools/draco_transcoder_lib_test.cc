@@ -141,6 +141,28 @@ TEST(DracoTranscoderTest, SimpleGlbInput) {
   ASSERT_GT(output_bin_size, 0);
 }
 
+// Tests using OBJ with materials as input.
+TEST(DracoTranscoderTest, SimpleObjInput) {
+  const std::string input_name = "textured_materials.obj";
+  const std::string input_filename = draco::GetTestFileFullPath(input_name);
+  const std::string output_filename =
+      draco::GetTestTempFileFullPath("test.gltf");
+
+  const draco::DracoTranscodingOptions options;
+  DRACO_ASSIGN_OR_ASSERT(std::unique_ptr<draco::DracoTranscoder> dt,
+                         draco::DracoTranscoder::Create(options));
+
+  draco::DracoTranscoder::FileOptions file_options;
+  file_options.input_filename = input_filename;
+  file_options.output_filename = output_filename;
+  DRACO_ASSERT_OK(dt->Transcode(file_options));
+
+  const std::string output_bin_filename =
+      draco::GetTestTempFileFullPath("test.bin");
+  const size_t output_bin_size = draco::GetFileSize(output_bin_filename);
+  ASSERT_GT(output_bin_size, 0);
+}
+
 // Simple test to check glb input and setting smaller position quantizations
 // outputs a smaller file overall.
 TEST(DracoTranscoderTest, TestPositionQuantization) {